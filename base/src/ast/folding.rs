@@ -0,0 +1,83 @@
+//! Folding and selection ranges, derived straight from AST nesting. KerML's
+//! grammar doesn't distinguish "foldable" constructs from any other block,
+//! so every node with children is a folding candidate and every span
+//! containing a position is a selection-range ancestor.
+
+use crate::ast::AstNode;
+use crate::span::{Position, Span};
+
+/// Spans of every node with at least one child, the regions a KerML editor
+/// can fold. Leaf declarations (e.g. an `attribute` with no body) have
+/// nothing to collapse and are skipped.
+pub fn extract_kerml_folding_ranges(root: &AstNode) -> Vec<Span> {
+    let mut spans = Vec::new();
+    collect_foldable(root, &mut spans);
+    spans
+}
+
+fn collect_foldable(node: &AstNode, spans: &mut Vec<Span>) {
+    if !node.children.is_empty() {
+        spans.push(node.span);
+    }
+    for child in &node.children {
+        collect_foldable(child, spans);
+    }
+}
+
+/// Every span enclosing `position`, innermost first, for `textDocument/selectionRange`.
+/// Each entry becomes a `SelectionRange` whose `parent` is the next entry.
+pub fn find_kerml_selection_spans(root: &AstNode, position: Position) -> Vec<Span> {
+    let mut chain = Vec::new();
+    collect_chain(root, position, &mut chain);
+    chain.reverse();
+    chain
+}
+
+fn collect_chain(node: &AstNode, position: Position, chain: &mut Vec<Span>) {
+    if !node.span.contains(position) {
+        return;
+    }
+    chain.push(node.span);
+    for child in &node.children {
+        collect_chain(child, position, chain);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::SymbolKind;
+
+    fn pos(line: u32, column: u32) -> Position {
+        Position::new(line, column)
+    }
+
+    fn span(start: (u32, u32), end: (u32, u32)) -> Span {
+        Span::new(pos(start.0, start.1), pos(end.0, end.1))
+    }
+
+    fn sample_tree() -> AstNode {
+        AstNode::new("Vehicle", SymbolKind::PartDefinition, span((0, 0), (5, 1))).with_children(vec![
+            AstNode::new("engine", SymbolKind::PartUsage, span((1, 4), (3, 5))).with_children(vec![
+                AstNode::new("power", SymbolKind::AttributeUsage, span((2, 8), (2, 20))),
+            ]),
+        ])
+    }
+
+    #[test]
+    fn folding_ranges_include_every_node_with_children_but_not_leaves() {
+        let ranges = extract_kerml_folding_ranges(&sample_tree());
+        assert_eq!(ranges, vec![span((0, 0), (5, 1)), span((1, 4), (3, 5))]);
+    }
+
+    #[test]
+    fn selection_spans_are_ordered_innermost_to_outermost() {
+        let spans = find_kerml_selection_spans(&sample_tree(), pos(2, 10));
+        assert_eq!(spans, vec![span((2, 8), (2, 20)), span((1, 4), (3, 5)), span((0, 0), (5, 1))]);
+    }
+
+    #[test]
+    fn a_position_outside_every_span_yields_no_chain() {
+        assert!(find_kerml_selection_spans(&sample_tree(), pos(10, 0)).is_empty());
+    }
+}
@@ -0,0 +1,100 @@
+//! The syntax-layer AST: immutable tree produced by the parser, with no
+//! cross-file knowledge or resolved references (see the crate-level
+//! three-phase pipeline rule).
+
+use crate::span::Span;
+
+pub mod folding;
+pub mod scan;
+pub mod symbol_kind;
+pub use folding::{extract_kerml_folding_ranges, find_kerml_selection_spans};
+pub use scan::scan_block_structure;
+pub use symbol_kind::SymbolKind;
+
+/// A declaration node in the AST. Usages/definitions of every kind share
+/// this shape; what differs is `kind` and whether `children` holds nested
+/// members.
+#[derive(Debug, Clone)]
+pub struct AstNode {
+    pub name: String,
+    pub kind: SymbolKind,
+    pub span: Span,
+    pub children: Vec<AstNode>,
+}
+
+impl AstNode {
+    pub fn new(name: impl Into<String>, kind: SymbolKind, span: Span) -> Self {
+        Self { name: name.into(), kind, span, children: Vec::new() }
+    }
+
+    pub fn with_children(mut self, children: Vec<AstNode>) -> Self {
+        self.children = children;
+        self
+    }
+
+    /// Strips spans, recursively, for golden-file comparison — see
+    /// [`NormalizedAst`].
+    pub fn normalize(&self) -> NormalizedAst {
+        NormalizedAst { name: self.name.clone(), kind: self.kind, children: self.children.iter().map(AstNode::normalize).collect() }
+    }
+}
+
+/// [`AstNode`] with spans stripped, so a structural/golden comparison of
+/// two trees only fails when the nesting, names, or kinds actually differ
+/// — not because the same construct now starts one column over. Maintainers
+/// regenerate a golden by dumping [`Self`] via `syster dump-ast
+/// --normalized` and committing the result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct NormalizedAst {
+    pub name: String,
+    pub kind: SymbolKind,
+    pub children: Vec<NormalizedAst>,
+}
+
+/// Extension point for walking an [`AstNode`] tree. The default symbol
+/// extraction (declaration -> [`Symbol`](crate::semantic::symbol::Symbol))
+/// is itself just an `AstVisitor` impl, so callers needing custom
+/// extraction (e.g. collecting only `part def`s, or building an index of
+/// doc comments) can implement this trait instead of re-walking the tree.
+pub trait AstVisitor {
+    /// Called once per node, before its children are visited. Return
+    /// `false` to skip descending into this node's children.
+    fn visit(&mut self, node: &AstNode) -> bool {
+        let _ = node;
+        true
+    }
+}
+
+pub fn walk(node: &AstNode, visitor: &mut dyn AstVisitor) {
+    if visitor.visit(node) {
+        for child in &node.children {
+            walk(child, visitor);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct NameCollector(Vec<String>);
+    impl AstVisitor for NameCollector {
+        fn visit(&mut self, node: &AstNode) -> bool {
+            self.0.push(node.name.clone());
+            true
+        }
+    }
+
+    #[test]
+    fn custom_visitor_collects_every_node_name() {
+        let span = Span::new(crate::span::Position::new(0, 0), crate::span::Position::new(0, 1));
+        let tree = AstNode::new("Vehicle", SymbolKind::PartDefinition, span)
+            .with_children(vec![AstNode::new("engine", SymbolKind::PartUsage, span)]);
+
+        let mut collector = NameCollector(Vec::new());
+        walk(&tree, &mut collector);
+
+        assert_eq!(collector.0, vec!["Vehicle".to_string(), "engine".to_string()]);
+    }
+}
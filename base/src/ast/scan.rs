@@ -0,0 +1,129 @@
+//! A minimal line-based structural scanner standing in for a real grammar
+//! (see [`crate::parser::recovery`]'s stubs) until one is wired in. Backs
+//! `syster dump-ast` and this module's golden test.
+//!
+//! Recognizes only two line shapes: a `<keyword> <name> {` block (pushes a
+//! node and descends into it) and a `<keyword> <name>;` leaf declaration —
+//! enough to reproduce the nesting of a representative model fragment, not
+//! to parse real grammar constructs. A line that's neither (an expression
+//! inside a constraint body, a multiplicity, ...) is silently skipped
+//! rather than guessed at.
+
+use super::symbol_kind::SymbolKind;
+use super::AstNode;
+use crate::span::{Position, Span};
+
+/// Keyword phrases recognized at the start of a declaration line, longest
+/// first so `"requirement def"` matches before the bare `"requirement"`
+/// does. `subject`/`constraint` map to the same [`SymbolKind::AttributeUsage`]
+/// a plain usage declaration would, since at this syntax layer they're the
+/// same shape — "this usage is the subject" only becomes a
+/// [`crate::semantic::relationship_graph::RelationshipKind::Subject`] edge
+/// during semantic extraction, the same precedent
+/// [`crate::workspace::Workspace::record_constraint_references`]'s tests
+/// already set for `constraint` usages.
+const KEYWORDS: &[(&str, SymbolKind)] = &[
+    ("requirement def", SymbolKind::RequirementDefinition),
+    ("requirement", SymbolKind::RequirementUsage),
+    ("part def", SymbolKind::PartDefinition),
+    ("part", SymbolKind::PartUsage),
+    ("port def", SymbolKind::PortDefinition),
+    ("port", SymbolKind::PortUsage),
+    ("action def", SymbolKind::ActionDefinition),
+    ("action", SymbolKind::ActionUsage),
+    ("package", SymbolKind::Package),
+    ("assert constraint", SymbolKind::AttributeUsage),
+    ("constraint", SymbolKind::AttributeUsage),
+    ("subject", SymbolKind::AttributeUsage),
+    ("attribute", SymbolKind::AttributeUsage),
+];
+
+fn match_keyword(line: &str) -> Option<(SymbolKind, &str)> {
+    KEYWORDS.iter().find_map(|(keyword, kind)| line.strip_prefix(keyword).map(|rest| (*kind, rest)))
+}
+
+/// Whatever's left of a line after its keyword and before `:`/`{`/`;`.
+fn extract_name(rest: &str) -> String {
+    rest.split(':').next().unwrap_or("").trim().to_string()
+}
+
+/// Scans `content` into a forest of top-level [`AstNode`]s. Every node's
+/// span is a zero-width placeholder at `(0, 0)` — this scanner tracks
+/// nesting, not source positions — so callers needing a real span should
+/// normalize (see [`AstNode::normalize`]) before comparing, or not rely on
+/// this scanner at all once a real grammar lands.
+pub fn scan_block_structure(content: &str) -> Vec<AstNode> {
+    let placeholder = Span::new(Position::new(0, 0), Position::new(0, 0));
+    let mut roots: Vec<AstNode> = Vec::new();
+    let mut stack: Vec<AstNode> = Vec::new();
+
+    for raw_line in content.lines() {
+        let line = raw_line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line == "}" {
+            if let Some(node) = stack.pop() {
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(node),
+                    None => roots.push(node),
+                }
+            }
+            continue;
+        }
+        if let Some(body) = line.strip_suffix('{') {
+            if let Some((kind, rest)) = match_keyword(body.trim()) {
+                stack.push(AstNode::new(extract_name(rest), kind, placeholder));
+            }
+            continue;
+        }
+        if let Some(body) = line.strip_suffix(';') {
+            if let Some((kind, rest)) = match_keyword(body.trim()) {
+                let leaf = AstNode::new(extract_name(rest), kind, placeholder);
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(leaf),
+                    None => roots.push(leaf),
+                }
+            }
+        }
+    }
+
+    roots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_requirement_with_a_subject_and_a_constraint_matches_its_golden_normalized_tree() {
+        let content = "\
+requirement def VehicleRange {
+    subject vehicle : Vehicle;
+    assert constraint {
+        range >= 300
+    }
+}";
+
+        let roots = scan_block_structure(content);
+        assert_eq!(roots.len(), 1);
+        let normalized = roots[0].normalize();
+
+        let golden = crate::ast::AstNode::new("VehicleRange", SymbolKind::RequirementDefinition, Span::new(Position::new(0, 0), Position::new(0, 0)))
+            .with_children(vec![
+                crate::ast::AstNode::new("vehicle", SymbolKind::AttributeUsage, Span::new(Position::new(1, 0), Position::new(1, 0))),
+                crate::ast::AstNode::new("", SymbolKind::AttributeUsage, Span::new(Position::new(2, 0), Position::new(2, 0))),
+            ])
+            .normalize();
+
+        assert_eq!(normalized, golden);
+    }
+
+    #[test]
+    fn a_line_that_matches_no_known_keyword_is_skipped_rather_than_guessed_at() {
+        let roots = scan_block_structure("package Vehicle {\n    engine from somewhere;\n}");
+
+        assert_eq!(roots.len(), 1);
+        assert!(roots[0].children.is_empty());
+    }
+}
@@ -0,0 +1,57 @@
+//! The SysML v2 / KerML construct a declaration node was parsed as.
+//!
+//! Lives in the syntax layer (not `semantic`) since it's purely a
+//! classification of what was parsed, needed by [`AstNode`](super::AstNode)
+//! before any cross-file resolution happens; `semantic::symbol` re-exports
+//! it so existing callers don't need to change their imports.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub enum SymbolKind {
+    Package,
+    PartDefinition,
+    PartUsage,
+    PortDefinition,
+    PortUsage,
+    ActionDefinition,
+    ActionUsage,
+    AttributeUsage,
+    Connection,
+    Interface,
+    EnumerationDefinition,
+    RequirementDefinition,
+    RequirementUsage,
+    /// An `enumerated_value` (or `enumeration_usage_member`) declared inside
+    /// an [`EnumerationDefinition`](SymbolKind::EnumerationDefinition).
+    EnumeratedValue,
+    /// A `variation part def`/`variation action def`/etc.
+    VariationDefinition,
+    /// A `variant` member of an enclosing [`VariationDefinition`](SymbolKind::VariationDefinition).
+    VariantUsage,
+    /// An `alias MyAlias for Target;` declaration.
+    Alias,
+    /// A `state def` declaration.
+    StateDefinition,
+    /// A `state` usage, whose body can hold `entry`/`exit`/`do` actions and
+    /// `transition`s to sibling states.
+    StateUsage,
+    /// The loop variable introduced by `for x in items { ... }`, scoped to
+    /// the `for` loop's own body rather than the enclosing action — it's
+    /// declared as a nested member of the loop itself, so resolution
+    /// inside the body finds it and resolution after the loop doesn't.
+    LoopVariable,
+    /// A `snapshot s;` portion usage, relating a point-in-time slice to its
+    /// owning occurrence.
+    SnapshotUsage,
+    /// A `timeslice t;` portion usage, relating a duration slice to its
+    /// owning occurrence.
+    TimesliceUsage,
+    /// A `view` usage, which can `expose` another namespace's members into
+    /// its own scope.
+    ViewUsage,
+    /// A `concern def` declaration, the target of a requirement's `frame
+    /// <concern>;` declaration.
+    ConcernDefinition,
+    /// A `concern` usage.
+    ConcernUsage,
+}
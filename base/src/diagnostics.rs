@@ -0,0 +1,1116 @@
+//! Diagnostics produced by semantic validation passes, independent of the
+//! LSP — the CLI and language server both render these, just differently.
+
+use std::path::PathBuf;
+
+use crate::semantic::qualified_name::QualifiedName;
+use crate::span::Span;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+    Information,
+    Hint,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub span: Span,
+    pub file: PathBuf,
+    /// A stable rule identifier, e.g. `invalid-typing-by-usage`, so tooling (the CLI
+    /// `--explain` command, IDE quick-fixes) can key off it.
+    pub code: &'static str,
+    /// Secondary locations relevant to this diagnostic, e.g. the stdlib
+    /// declaration a shadowing user definition collides with. Rendered as
+    /// LSP `relatedInformation`.
+    pub related: Vec<RelatedLocation>,
+}
+
+/// A secondary location attached to a [`Diagnostic`], with a message
+/// explaining its relevance (e.g. "standard library declaration of 'Real'").
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelatedLocation {
+    pub file: PathBuf,
+    pub span: Span,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, code: &'static str, message: impl Into<String>, file: PathBuf, span: Span) -> Self {
+        Self { severity, message: message.into(), span, file, code, related: Vec::new() }
+    }
+
+    /// Attaches a secondary location, e.g. the stdlib declaration a
+    /// shadowing user definition collides with.
+    pub fn with_related(mut self, file: PathBuf, span: Span, message: impl Into<String>) -> Self {
+        self.related.push(RelatedLocation { file, span, message: message.into() });
+        self
+    }
+}
+
+/// `invalid-typing-by-usage`: a usage's `typed by` target must name a
+/// definition, not another usage (e.g. `part p : engine;` where `engine`
+/// is itself a `part` usage rather than a `part def`).
+pub const TYPED_BY_USAGE: &str = "invalid-typing-by-usage";
+
+/// Flags every usage in `workspace` whose `typed by` target resolves to a
+/// usage rather than a definition.
+pub fn check_typing(workspace: &crate::workspace::Workspace) -> Vec<Diagnostic> {
+    use crate::semantic::relationship_graph::RelationshipKind;
+    use crate::semantic::symbol::SymbolKind;
+
+    let mut diagnostics = Vec::new();
+    for symbol in workspace.symbols() {
+        for target_name in workspace.relationships.edges(RelationshipKind::Typing, &symbol.qualified_name) {
+            let Some(target) = workspace.symbol_by_qualified_name(target_name) else { continue };
+            if is_usage_kind(target.kind) {
+                diagnostics.push(Diagnostic::new(
+                    Severity::Error,
+                    TYPED_BY_USAGE,
+                    format!("'{}' is typed by '{}', which is a usage, not a definition", symbol.qualified_name, target_name),
+                    symbol.file.clone(),
+                    symbol.range(),
+                ));
+            }
+        }
+    }
+    diagnostics
+}
+
+/// `unresolved-framed-concern`: a requirement's `frame <concern>;` names a
+/// symbol that resolves, but not to a `concern def`/`concern` usage.
+/// Reported separately from [`UNRESOLVED_REFERENCE`] (which already covers
+/// a `frame` target that doesn't resolve at all), the same way
+/// [`TYPED_BY_USAGE`] is split from the generic unresolved-reference check.
+pub const FRAME_TARGET_NOT_A_CONCERN: &str = "unresolved-framed-concern";
+
+/// Flags every `frame <concern>;` whose target resolves to something other
+/// than a [`ConcernDefinition`](crate::semantic::symbol::SymbolKind::ConcernDefinition)
+/// or [`ConcernUsage`](crate::semantic::symbol::SymbolKind::ConcernUsage).
+pub fn check_framed_concerns(workspace: &crate::workspace::Workspace) -> Vec<Diagnostic> {
+    use crate::semantic::relationship_graph::RelationshipKind;
+    use crate::semantic::symbol::SymbolKind;
+
+    let mut diagnostics = Vec::new();
+    for symbol in workspace.symbols() {
+        for target_name in workspace.relationships.edges(RelationshipKind::Frame, &symbol.qualified_name) {
+            let Some(target) = workspace.symbol_by_qualified_name(target_name) else { continue };
+            if !matches!(target.kind, SymbolKind::ConcernDefinition | SymbolKind::ConcernUsage) {
+                diagnostics.push(Diagnostic::new(
+                    Severity::Error,
+                    FRAME_TARGET_NOT_A_CONCERN,
+                    format!("'{}' frames '{}', which is not a concern", symbol.qualified_name, target_name),
+                    symbol.file.clone(),
+                    symbol.range(),
+                ));
+            }
+        }
+    }
+    diagnostics
+}
+
+/// `mismatched-connection-end-multiplicity`: a connection/interface's two
+/// ends declare different multiplicities.
+pub const MISMATCHED_END_MULTIPLICITY: &str = "mismatched-connection-end-multiplicity";
+
+/// `mismatched-connection-end-conjugation`: exactly one end of a
+/// connection/interface is conjugated (`~Port`), which can't type-check
+/// against the other end.
+pub const MISMATCHED_END_CONJUGATION: &str = "mismatched-connection-end-conjugation";
+
+/// Flags multiplicity and conjugation mismatches between a connection's
+/// two declared ends.
+pub fn check_connection_ends(workspace: &crate::workspace::Workspace) -> Vec<Diagnostic> {
+    use crate::semantic::relationship_graph::RelationshipKind;
+    use crate::semantic::symbol::SymbolKind;
+
+    let mut diagnostics = Vec::new();
+    for symbol in workspace.symbols() {
+        if !matches!(symbol.kind, SymbolKind::Connection | SymbolKind::Interface) {
+            continue;
+        }
+        let ends = workspace.relationships.edges(RelationshipKind::ConnectionEnd, &symbol.qualified_name);
+        let [a, b] = match ends {
+            [a, b] => [a, b],
+            _ => continue,
+        };
+
+        let (ma, mb) = (workspace.multiplicities.multiplicity(a), workspace.multiplicities.multiplicity(b));
+        if let (Some(ma), Some(mb)) = (ma, mb) {
+            if ma != mb {
+                diagnostics.push(Diagnostic::new(
+                    Severity::Error,
+                    MISMATCHED_END_MULTIPLICITY,
+                    format!("connection ends '{a}' and '{b}' declare different multiplicities"),
+                    symbol.file.clone(),
+                    symbol.range(),
+                ));
+            }
+        }
+
+        let (ca, cb) = (workspace.multiplicities.is_conjugated(a), workspace.multiplicities.is_conjugated(b));
+        if ca != cb {
+            diagnostics.push(Diagnostic::new(
+                Severity::Error,
+                MISMATCHED_END_CONJUGATION,
+                format!("connection ends '{a}' and '{b}' must either both or neither be conjugated"),
+                symbol.file.clone(),
+                symbol.range(),
+            ));
+        }
+    }
+    diagnostics
+}
+
+fn is_usage_kind(kind: crate::semantic::symbol::SymbolKind) -> bool {
+    use crate::semantic::symbol::SymbolKind::*;
+    matches!(
+        kind,
+        PartUsage | PortUsage | ActionUsage | AttributeUsage | Connection | Interface | RequirementUsage | StateUsage | LoopVariable | SnapshotUsage | TimesliceUsage | ViewUsage | ConcernUsage
+    )
+}
+
+/// `empty-package`: opt-in, since an empty package is often a
+/// work-in-progress stub rather than a mistake.
+pub const EMPTY_PACKAGE: &str = "empty-package";
+
+/// `unused-definition`: opt-in, since the check can only see references
+/// within the loaded workspace and will false-positive on anything meant
+/// to be consumed from outside it (hence the `is_exported` exemption).
+pub const UNUSED_DEFINITION: &str = "unused-definition";
+
+fn is_definition_kind(kind: crate::semantic::symbol::SymbolKind) -> bool {
+    use crate::semantic::symbol::SymbolKind::*;
+    matches!(kind, PartDefinition | PortDefinition | ActionDefinition | EnumerationDefinition | RequirementDefinition | VariationDefinition | StateDefinition | ConcernDefinition)
+}
+
+/// Flags packages with no members. Off by default; callers opt in by
+/// calling this at all and choosing `severity` (mirroring the LSP client's
+/// `diagnosticSeverity` setting).
+pub fn check_empty_packages(workspace: &crate::workspace::Workspace, severity: Severity) -> Vec<Diagnostic> {
+    use crate::semantic::symbol::SymbolKind;
+
+    workspace
+        .symbols()
+        .iter()
+        .filter(|s| s.kind == SymbolKind::Package && workspace.children_of(&s.qualified_name).is_empty())
+        .map(|s| Diagnostic::new(severity, EMPTY_PACKAGE, format!("package '{}' has no members", s.qualified_name), s.file.clone(), s.range()))
+        .collect()
+}
+
+/// Every [`RelationshipKind`](crate::semantic::relationship_graph::RelationshipKind)
+/// a reference edge can be recorded under — shared by [`ReferenceCollector`]
+/// and anywhere else that needs "every kind that can point at a name"
+/// rather than one specific relationship.
+const REFERENCE_EDGE_KINDS: [crate::semantic::relationship_graph::RelationshipKind; 17] = {
+    use crate::semantic::relationship_graph::RelationshipKind::*;
+    [Specialization, Typing, Subsetting, Redefinition, Subject, About, Satisfy, Assert, Verify, ConnectionEnd, Import, Bind, AllocationEnd, SuccessionEnd, ResultExpression, ConstraintReference, Frame]
+};
+
+/// Gathers every qualified name targeted by some relationship edge, so
+/// [`check_unused_definitions`] can tell a real use apart from a definition
+/// nobody points at.
+///
+/// Reference counted per target rather than a plain set, so a single file's
+/// contribution can be dropped and recomputed in isolation
+/// ([`Self::remove_file`]/[`Self::add_file`]) without losing a target that's
+/// still referenced by some *other* loaded file — the same incremental
+/// per-file update [`crate::workspace::Workspace::insert_symbol`]/
+/// [`crate::workspace::Workspace::remove_file`] already give the symbol
+/// table, applied here to the reference index instead of a full
+/// [`Self::build`] rescan.
+///
+/// `pub(crate)` so [`crate::workspace::Workspace`] can hold one statefully
+/// and keep it current as files come and go, instead of
+/// [`check_unused_definitions`] rebuilding it from scratch on every call —
+/// see [`crate::workspace::Workspace::refresh_references_for_file`].
+#[derive(Debug, Default)]
+pub(crate) struct ReferenceCollector {
+    referenced: std::collections::HashMap<QualifiedName, usize>,
+}
+
+impl ReferenceCollector {
+    pub(crate) fn build(workspace: &crate::workspace::Workspace) -> Self {
+        let mut collector = Self { referenced: std::collections::HashMap::new() };
+        for symbol in workspace.symbols() {
+            collector.add_symbol(workspace, symbol);
+        }
+        collector
+    }
+
+    fn add_symbol(&mut self, workspace: &crate::workspace::Workspace, symbol: &crate::semantic::symbol::Symbol) {
+        for kind in REFERENCE_EDGE_KINDS {
+            for target in workspace.relationships.edges(kind, &symbol.qualified_name) {
+                *self.referenced.entry(target.clone()).or_insert(0) += 1;
+            }
+        }
+    }
+
+    /// Drops `file`'s contribution to the reference index, e.g. right
+    /// before it's reparsed. A target another file still references keeps
+    /// its count above zero and stays reported as referenced.
+    pub(crate) fn remove_file(&mut self, workspace: &crate::workspace::Workspace, file: &std::path::Path) {
+        for symbol in workspace.symbols().iter().filter(|s| s.file == file) {
+            for kind in REFERENCE_EDGE_KINDS {
+                for target in workspace.relationships.edges(kind, &symbol.qualified_name) {
+                    if let std::collections::hash_map::Entry::Occupied(mut entry) = self.referenced.entry(target.clone()) {
+                        *entry.get_mut() -= 1;
+                        if *entry.get() == 0 {
+                            entry.remove();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Adds `file`'s current contribution to the reference index, e.g.
+    /// after it's been reparsed and its symbols/edges reinserted. Pairs
+    /// with [`Self::remove_file`] to recompute a single file's references
+    /// without rescanning the rest of the workspace.
+    pub(crate) fn add_file(&mut self, workspace: &crate::workspace::Workspace, file: &std::path::Path) {
+        for symbol in workspace.symbols().iter().filter(|s| s.file == file) {
+            self.add_symbol(workspace, symbol);
+        }
+    }
+
+    pub(crate) fn is_referenced(&self, name: &QualifiedName) -> bool {
+        self.referenced.contains_key(name)
+    }
+}
+
+/// Flags top-level definitions that nothing in the workspace references,
+/// skipping stdlib declarations (nothing loaded ever "uses" them in the
+/// sense this check cares about) and anything marked `public` (it may be
+/// used by consumers outside this workspace). Off by default.
+pub fn check_unused_definitions(workspace: &crate::workspace::Workspace, severity: Severity) -> Vec<Diagnostic> {
+    workspace
+        .symbols()
+        .iter()
+        .filter(|s| is_definition_kind(s.kind))
+        .filter(|s| s.qualified_name.parent().is_none())
+        .filter(|s| !workspace.is_library_file(&s.file))
+        .filter(|s| !workspace.is_exported(&s.qualified_name))
+        .filter(|s| !workspace.is_referenced(&s.qualified_name))
+        .map(|s| Diagnostic::new(severity, UNUSED_DEFINITION, format!("'{}' is never referenced in this workspace", s.qualified_name), s.file.clone(), s.range()))
+        .collect()
+}
+
+/// `stdlib-shadowing`: a top-level user definition shares a simple name
+/// with a visible stdlib primitive.
+pub const STDLIB_SHADOWING: &str = "stdlib-shadowing";
+
+/// Flags top-level user definitions that share a simple name and kind with
+/// a visible stdlib primitive (e.g. `part def Real;`, which silently
+/// changes what existing `: Real` references resolve to). Off by default.
+///
+/// Only top-level names are checked: `MyPkg::Real` is an unambiguous,
+/// intentionally distinct declaration under its own namespace, not a
+/// shadow, so it's skipped — as is any declaration that lives in the
+/// stdlib file itself.
+pub fn check_stdlib_shadowing(workspace: &crate::workspace::Workspace, severity: Severity) -> Vec<Diagnostic> {
+    workspace
+        .symbols()
+        .iter()
+        .filter(|s| s.qualified_name.parent().is_none())
+        .filter(|s| !workspace.is_library_file(&s.file))
+        .filter_map(|s| {
+            let stdlib = crate::semantic::stdlib::lookup_primitive(&s.qualified_name)?;
+            if stdlib.kind != s.kind {
+                return None;
+            }
+            Some(
+                Diagnostic::new(
+                    severity,
+                    STDLIB_SHADOWING,
+                    format!("'{}' shadows the standard library primitive of the same name", s.qualified_name),
+                    s.file.clone(),
+                    s.range(),
+                )
+                .with_related(stdlib.file.clone(), stdlib.range(), format!("standard library declaration of '{}'", stdlib.qualified_name)),
+            )
+        })
+        .collect()
+}
+
+/// `unresolved-succession-endpoint`: a `first <source> then <target>;`
+/// succession whose `source` or `target` feature chain doesn't resolve
+/// within the enclosing behavior. Reported by
+/// [`crate::workspace::Workspace::record_succession`] at resolution time,
+/// rather than by scanning the relationship graph afterwards — an
+/// unresolved chain has no target name to attach a dangling edge to.
+pub const UNRESOLVED_SUCCESSION_ENDPOINT: &str = "unresolved-succession-endpoint";
+
+/// `unresolved-result-expression`: a `calc def`'s trailing result
+/// expression references a feature that doesn't resolve within the
+/// enclosing calc. Reported by
+/// [`crate::workspace::Workspace::record_result_expression`] at resolution
+/// time, for the same reason [`UNRESOLVED_SUCCESSION_ENDPOINT`] is: an
+/// unresolved chain has no target name to attach a dangling edge to.
+pub const UNRESOLVED_RESULT_EXPRESSION: &str = "unresolved-result-expression";
+
+/// `specialization-cycle`: a definition specializes itself, directly or
+/// through a chain of other definitions.
+pub const SPECIALIZATION_CYCLE: &str = "specialization-cycle";
+
+/// Flags every symbol whose `specializes` chain loops back on itself.
+/// [`crate::semantic::relationship_graph::RelationshipGraph::specialization_chain`]
+/// already stops cleanly at a cycle rather than looping forever; this turns
+/// that silent stop into a reportable finding.
+pub fn check_specialization_cycles(workspace: &crate::workspace::Workspace) -> Vec<Diagnostic> {
+    workspace
+        .symbols()
+        .iter()
+        .filter(|s| workspace.relationships.has_specialization_cycle(&s.qualified_name))
+        .map(|s| {
+            Diagnostic::new(
+                Severity::Error,
+                SPECIALIZATION_CYCLE,
+                format!("'{}' specializes itself, directly or indirectly", s.qualified_name),
+                s.file.clone(),
+                s.range(),
+            )
+        })
+        .collect()
+}
+
+/// `conflicting-redefinition`: more than one member of the same
+/// definition redefines (`:>>`) the same inherited feature — each
+/// redefinition should narrow a distinct feature, so two members
+/// redefining the same target shadow each other rather than independently
+/// overriding it.
+pub const CONFLICTING_REDEFINITION: &str = "conflicting-redefinition";
+
+/// Groups every [`RelationshipKind::Redefinition`](crate::semantic::relationship_graph::RelationshipKind::Redefinition)
+/// edge by `(owning definition, resolved target)` and flags any group with
+/// more than one member, pointing at every offender via
+/// [`Diagnostic::with_related`].
+pub fn check_conflicting_redefinitions(workspace: &crate::workspace::Workspace) -> Vec<Diagnostic> {
+    use crate::semantic::relationship_graph::RelationshipKind;
+    use std::collections::HashMap;
+
+    let mut order: Vec<(QualifiedName, QualifiedName)> = Vec::new();
+    let mut by_scope_and_target: HashMap<(QualifiedName, QualifiedName), Vec<&crate::semantic::symbol::Symbol>> = HashMap::new();
+    for symbol in workspace.symbols() {
+        let Some(scope) = symbol.qualified_name.parent() else { continue };
+        for target in workspace.relationships.edges(RelationshipKind::Redefinition, &symbol.qualified_name) {
+            let key = (scope.clone(), target.clone());
+            by_scope_and_target.entry(key.clone()).or_insert_with(|| { order.push(key.clone()); Vec::new() }).push(symbol);
+        }
+    }
+
+    order
+        .into_iter()
+        .filter_map(|key| {
+            let members = &by_scope_and_target[&key];
+            if members.len() < 2 {
+                return None;
+            }
+            let target = &key.1;
+            let (first, rest) = members.split_first().unwrap();
+            let mut diagnostic = Diagnostic::new(
+                Severity::Error,
+                CONFLICTING_REDEFINITION,
+                format!("'{target}' is redefined by more than one member: '{}' and '{}'", first.qualified_name, rest[0].qualified_name),
+                first.file.clone(),
+                first.range(),
+            );
+            for other in rest {
+                diagnostic = diagnostic.with_related(other.file.clone(), other.range(), format!("also redefines '{target}' here"));
+            }
+            Some(diagnostic)
+        })
+        .collect()
+}
+
+/// `unresolved-reference`: a relationship edge (`specializes`, `: Type`,
+/// `subsets`, `redefines`, `subject`, `about`, `satisfy`/`assert`/`verify`,
+/// a connection end, `import`, `bind`, `allocate`, a succession end, a
+/// calc's result expression, a reference inside an `assert`/`assume`/
+/// `require constraint`'s expression body, or a `frame`) whose target names neither a
+/// symbol loaded into the workspace nor a standard library primitive —
+/// almost always a typo or a missing `import`.
+pub const UNRESOLVED_REFERENCE: &str = "unresolved-reference";
+
+/// One relationship edge whose target doesn't resolve against the loaded
+/// workspace or a stdlib primitive — the raw data behind
+/// [`check_unresolved_references`]'s [`Diagnostic`]s, for embedders that
+/// want a "broken links" report rather than CI-oriented findings. See
+/// [`unresolved_references`] and
+/// [`crate::workspace::Workspace::unresolved_references`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnresolvedRef {
+    /// The target name as referenced — what failed to resolve.
+    pub reference: QualifiedName,
+    pub file: PathBuf,
+    pub span: Span,
+    /// The qualified name of the symbol whose relationship edge this
+    /// reference came from — the scope resolution was attempted in.
+    pub scope: QualifiedName,
+}
+
+/// Every relationship edge whose target can't be resolved, as raw
+/// [`UnresolvedRef`]s. Already accounts for lazy stdlib loading: a target
+/// [`crate::semantic::stdlib::lookup_primitive`] would resolve is never
+/// included here, matching [`check_unresolved_references`] (which wraps
+/// this in [`Diagnostic`]s for CI gating).
+pub fn unresolved_references(workspace: &crate::workspace::Workspace) -> Vec<UnresolvedRef> {
+    use crate::semantic::relationship_graph::RelationshipKind::*;
+
+    let mut refs = Vec::new();
+    for symbol in workspace.symbols() {
+        for kind in [Specialization, Typing, Subsetting, Redefinition, Subject, About, Satisfy, Assert, Verify, ConnectionEnd, Import, Bind, AllocationEnd, SuccessionEnd, ResultExpression, ConstraintReference, Frame] {
+            for target in workspace.relationships.edges(kind, &symbol.qualified_name) {
+                if workspace.symbol_by_qualified_name(target).is_some() {
+                    continue;
+                }
+                if crate::semantic::stdlib::lookup_primitive(target).is_some() {
+                    continue;
+                }
+                refs.push(UnresolvedRef {
+                    reference: target.clone(),
+                    file: symbol.file.clone(),
+                    span: symbol.range(),
+                    scope: symbol.qualified_name.clone(),
+                });
+            }
+        }
+    }
+    refs
+}
+
+/// Flags every relationship edge whose target can't be resolved.
+pub fn check_unresolved_references(workspace: &crate::workspace::Workspace) -> Vec<Diagnostic> {
+    unresolved_references(workspace)
+        .into_iter()
+        .map(|r| Diagnostic::new(Severity::Error, UNRESOLVED_REFERENCE, format!("'{}' cannot be resolved", r.reference), r.file, r.span))
+        .collect()
+}
+
+/// `parse-error`: a syntax error recovered while parsing, surfaced as a
+/// diagnostic alongside semantic findings rather than kept as a separate
+/// channel.
+pub const PARSE_ERROR: &str = "parse-error";
+
+/// Folds `workspace`'s recorded parse errors and `semantic` findings into
+/// one deduplicated list: exact `(file, span, message)` duplicates collapse
+/// to a single entry, and any semantic diagnostic whose span overlaps a
+/// parse error in the same file is dropped outright — the syntax there is
+/// already broken, so a semantic finding on top of it is downstream noise
+/// rather than an independent problem. Parse errors themselves are always
+/// kept, surfaced as [`PARSE_ERROR`] diagnostics.
+pub fn dedup_with_parse_errors(workspace: &crate::workspace::Workspace, semantic: Vec<Diagnostic>) -> Vec<Diagnostic> {
+    let mut seen = std::collections::HashSet::new();
+    let mut result = Vec::new();
+
+    for (file, error) in workspace.all_parse_errors() {
+        let diagnostic = Diagnostic::new(Severity::Error, PARSE_ERROR, error.message.clone(), file.to_path_buf(), error.span);
+        if seen.insert((diagnostic.file.clone(), diagnostic.span, diagnostic.message.clone())) {
+            result.push(diagnostic);
+        }
+    }
+
+    for diagnostic in semantic {
+        let key = (diagnostic.file.clone(), diagnostic.span, diagnostic.message.clone());
+        if seen.contains(&key) {
+            continue;
+        }
+        let shadowed_by_parse_error =
+            workspace.parse_errors(&diagnostic.file).iter().any(|error| error.span.overlaps(&diagnostic.span));
+        if shadowed_by_parse_error {
+            continue;
+        }
+        seen.insert(key);
+        result.push(diagnostic);
+    }
+
+    result
+}
+
+/// How many [`Diagnostic`]s [`ValidationReport::summary`] found under a
+/// given rule code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationSummary {
+    pub code: &'static str,
+    pub severity: Severity,
+    pub count: usize,
+}
+
+/// The result of [`run_validation_suite`]: every finding, plus a grouped
+/// summary for a one-stop CI report.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ValidationReport {
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl ValidationReport {
+    /// Whether any finding is error-severity. CI callers exit non-zero on
+    /// this rather than on an empty report, since some passes (`check_typing`
+    /// etc.) are always error-severity while future additions may not be.
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics.iter().any(|d| d.severity == Severity::Error)
+    }
+
+    /// Findings grouped by rule code, in first-seen order, with counts.
+    pub fn summary(&self) -> Vec<ValidationSummary> {
+        let mut order = Vec::new();
+        let mut counts: std::collections::HashMap<&'static str, (Severity, usize)> = std::collections::HashMap::new();
+        for d in &self.diagnostics {
+            let entry = counts.entry(d.code).or_insert_with(|| {
+                order.push(d.code);
+                (d.severity, 0)
+            });
+            entry.1 += 1;
+        }
+        order.into_iter().map(|code| {
+            let (severity, count) = counts[code];
+            ValidationSummary { code, severity, count }
+        }).collect()
+    }
+}
+
+/// Runs the validation passes suitable for CI gating: relationship
+/// validators ([`check_typing`], [`check_connection_ends`],
+/// [`check_framed_concerns`], [`check_conflicting_redefinitions`]), cycle
+/// detection ([`check_specialization_cycles`]), and unresolved-reference
+/// detection ([`check_unresolved_references`]). This is a one-stop report
+/// distinct from the opt-in heuristic checks ([`check_empty_packages`],
+/// [`check_unused_definitions`], [`check_stdlib_shadowing`]), which a
+/// caller enables selectively rather than treats as gating — those stay
+/// excluded here. The result is folded against recorded parse errors via
+/// [`dedup_with_parse_errors`], so a syntax error doesn't also show up as
+/// a confusingly separate semantic error at the same spot.
+pub fn run_validation_suite(workspace: &crate::workspace::Workspace) -> ValidationReport {
+    let mut diagnostics = check_typing(workspace);
+    diagnostics.extend(check_connection_ends(workspace));
+    diagnostics.extend(check_specialization_cycles(workspace));
+    diagnostics.extend(check_unresolved_references(workspace));
+    diagnostics.extend(check_framed_concerns(workspace));
+    diagnostics.extend(check_conflicting_redefinitions(workspace));
+    ValidationReport { diagnostics: dedup_with_parse_errors(workspace, diagnostics) }
+}
+
+/// Like [`run_validation_suite`], but restricted to `root_packages` and
+/// whatever they transitively import (see
+/// [`Workspace::files_reachable_from`](crate::workspace::Workspace::files_reachable_from)):
+/// a finding in a file outside that reachable set is dropped. For large
+/// workspaces containing several independent models, lets a caller (`syster
+/// analyze --root A::B`, an LSP `rootPackages` setting) see only the model
+/// it asked about.
+pub fn run_validation_suite_scoped(workspace: &crate::workspace::Workspace, root_packages: &[crate::semantic::qualified_name::QualifiedName]) -> ValidationReport {
+    run_analysis_scoped(workspace, AnalysisMode::Full, root_packages)
+}
+
+/// How thoroughly [`run_analysis`] should check a workspace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnalysisMode {
+    /// Parse errors only — skips every semantic pass ([`check_typing`],
+    /// [`check_connection_ends`], [`check_specialization_cycles`],
+    /// [`check_unresolved_references`]). For a fast editor-on-save or CI
+    /// check that only cares whether the file is well-formed.
+    SyntaxOnly,
+    /// Parse errors plus the full semantic validation suite
+    /// ([`run_validation_suite`]).
+    #[default]
+    Full,
+}
+
+/// Runs validation at `mode`'s thoroughness. `SyntaxOnly` reports recovered
+/// parse errors without touching any semantic pass, so a caller that only
+/// needs "does this file parse" skips the relationship-graph walks
+/// entirely; `Full` is exactly [`run_validation_suite`].
+pub fn run_analysis(workspace: &crate::workspace::Workspace, mode: AnalysisMode) -> ValidationReport {
+    match mode {
+        AnalysisMode::SyntaxOnly => {
+            let diagnostics = workspace
+                .all_parse_errors()
+                .map(|(file, error)| Diagnostic::new(Severity::Error, PARSE_ERROR, error.message.clone(), file.to_path_buf(), error.span))
+                .collect();
+            ValidationReport { diagnostics }
+        }
+        AnalysisMode::Full => run_validation_suite(workspace),
+    }
+}
+
+/// [`run_analysis`] restricted to `root_packages` and whatever they
+/// transitively import, the same scoping [`run_validation_suite_scoped`]
+/// applies to the full suite — an empty root list is unrestricted.
+pub fn run_analysis_scoped(workspace: &crate::workspace::Workspace, mode: AnalysisMode, root_packages: &[crate::semantic::qualified_name::QualifiedName]) -> ValidationReport {
+    let report = run_analysis(workspace, mode);
+    if root_packages.is_empty() {
+        return report;
+    }
+    let in_scope = workspace.files_reachable_from(root_packages);
+    ValidationReport { diagnostics: report.diagnostics.into_iter().filter(|d| in_scope.contains(&d.file)).collect() }
+}
+
+/// A human description and example for a diagnostic rule code, printed by
+/// `syster explain <code>` (mirroring `rustc --explain`). Returns `None`
+/// for an unrecognized code rather than an empty string, so the CLI can
+/// tell "no such rule" apart from "this rule has no explanation yet".
+pub fn explain(code: &str) -> Option<&'static str> {
+    match code {
+        TYPED_BY_USAGE => Some(
+            "A usage's `typed by` target must name a definition, not another usage.\n\n\
+             Example:\n    part engine : PartUsage; // wrong: PartUsage is itself a usage\n    part engine : Engine;    // right: Engine is a `part def`",
+        ),
+        FRAME_TARGET_NOT_A_CONCERN => Some(
+            "A requirement's `frame <concern>;` names a symbol that resolves, but\n\
+             not to a `concern def`/`concern` usage.\n\n\
+             Example:\n    part def NotAConcern;\n    requirement def R {\n        frame NotAConcern; // resolves, but isn't a concern\n    }",
+        ),
+        MISMATCHED_END_MULTIPLICITY => Some(
+            "A connection's two ends declare different multiplicities, so the\n\
+             connection can't consistently relate them.\n\n\
+             Example:\n    connection c {\n        end a : Port[1];\n        end b : Port[0..2]; // mismatched with `a`\n    }",
+        ),
+        MISMATCHED_END_CONJUGATION => Some(
+            "Exactly one end of a connection is conjugated (`~Port`). Both ends must\n\
+             either be conjugated or not, since a connection relates a port to its\n\
+             complement, not to another port of the same direction.\n\n\
+             Example:\n    connection c {\n        end a : ~Port; // conjugated\n        end b : Port;  // not — mismatched\n    }",
+        ),
+        EMPTY_PACKAGE => Some(
+            "A package declares no members. Often a work-in-progress stub, so this\n\
+             rule is opt-in rather than on by default.\n\n\
+             Example:\n    package Empty { } // no members",
+        ),
+        UNUSED_DEFINITION => Some(
+            "A top-level definition that nothing in the loaded workspace references.\n\
+             Exempt: anything marked `public`, or declared in a library file — this\n\
+             check only sees the files actually loaded, so it can't tell a consumer\n\
+             outside the workspace from dead code.\n\n\
+             Example:\n    part def Gearbox; // never referenced anywhere",
+        ),
+        STDLIB_SHADOWING => Some(
+            "A top-level user definition shares a simple name and kind with a visible\n\
+             standard library primitive, silently changing what existing references\n\
+             to that name resolve to.\n\n\
+             Example:\n    part def Real; // shadows the standard library's `Real`",
+        ),
+        SPECIALIZATION_CYCLE => Some(
+            "A definition specializes itself, directly or through a chain of other\n\
+             definitions, so its specialization chain never reaches a root.\n\n\
+             Example:\n    part def A :> B;\n    part def B :> A; // cycle",
+        ),
+        UNRESOLVED_REFERENCE => Some(
+            "A relationship (`specializes`, `: Type`, `subsets`, `redefines`,\n\
+             `subject`, `about`, `satisfy`/`assert`/`verify`, a connection end,\n\
+             `import`, `bind`, `allocate`, a succession end, a calc's result\n\
+             expression, a constraint expression reference, or `frame`) names a\n\
+             target that can't be\n\
+             resolved — almost always a typo or a missing `import`.\n\n\
+             Example:\n    part engine : Engien; // typo, doesn't resolve",
+        ),
+        UNRESOLVED_SUCCESSION_ENDPOINT => Some(
+            "A `first <source> then <target>;` succession's `source` or `target`\n\
+             feature chain doesn't resolve within the enclosing behavior.\n\n\
+             Example:\n    action Run {\n        first s1 then s2; // s2 isn't declared anywhere in Run\n    }",
+        ),
+        UNRESOLVED_RESULT_EXPRESSION => Some(
+            "A `calc def`'s trailing result expression references a feature that\n\
+             doesn't resolve within the enclosing calc.\n\n\
+             Example:\n    calc def Increment {\n        return : Counter;\n        in c : Counter;\n        d // typo, doesn't resolve\n    }",
+        ),
+        CONFLICTING_REDEFINITION => Some(
+            "More than one member of the same definition redefines (`:>>`) the same\n\
+             inherited feature. Each redefinition should narrow a distinct feature;\n\
+             two redefining the same target shadow each other instead of\n\
+             independently overriding it.\n\n\
+             Example:\n    part def Vehicle { redefines a :>> x; redefines b :>> x; }",
+        ),
+        PARSE_ERROR => Some(
+            "A syntax error recovered while parsing. Any semantic diagnostic at an\n\
+             overlapping location is suppressed, since it's almost always downstream\n\
+             noise from the same broken syntax rather than an independent problem.",
+        ),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::semantic::qualified_name::QualifiedName;
+    use crate::semantic::relationship_graph::RelationshipKind;
+    use crate::semantic::symbol::{Symbol, SymbolKind};
+    use crate::span::Position;
+    use crate::workspace::Workspace;
+    use std::path::PathBuf;
+
+    #[test]
+    fn flags_mismatched_multiplicity_and_conjugation_between_connection_ends() {
+        use crate::semantic::multiplicity::Multiplicity;
+
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::link"), SymbolKind::Connection, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::link::p1"), SymbolKind::PortUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::link::p2"), SymbolKind::PortUsage, file, span));
+        ws.relationships.add_edge(RelationshipKind::ConnectionEnd, QualifiedName::new("Vehicle::link"), QualifiedName::new("Vehicle::link::p1"));
+        ws.relationships.add_edge(RelationshipKind::ConnectionEnd, QualifiedName::new("Vehicle::link"), QualifiedName::new("Vehicle::link::p2"));
+        ws.multiplicities.set_multiplicity(QualifiedName::new("Vehicle::link::p1"), Multiplicity::exact(1));
+        ws.multiplicities.set_multiplicity(QualifiedName::new("Vehicle::link::p2"), Multiplicity::new(0, Some(2)));
+        ws.multiplicities.mark_conjugated(QualifiedName::new("Vehicle::link::p1"));
+
+        let diagnostics = check_connection_ends(&ws);
+
+        assert_eq!(diagnostics.iter().filter(|d| d.code == MISMATCHED_END_MULTIPLICITY).count(), 1);
+        assert_eq!(diagnostics.iter().filter(|d| d.code == MISMATCHED_END_CONJUGATION).count(), 1);
+    }
+
+    #[test]
+    fn flags_a_usage_typed_by_another_usage() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::p"), SymbolKind::PartUsage, file, span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::p"), QualifiedName::new("Vehicle::engine"));
+
+        let diagnostics = check_typing(&ws);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, TYPED_BY_USAGE);
+    }
+
+    #[test]
+    fn a_requirement_framing_a_concern_def_resolves_cleanly() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("SafetyConcern"), SymbolKind::ConcernDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("R1"), SymbolKind::RequirementDefinition, file, span));
+        ws.record_frame(QualifiedName::new("R1"), QualifiedName::new("SafetyConcern"));
+
+        assert!(ws.goto_definition_by_name(&QualifiedName::new("SafetyConcern")).is_some());
+        assert!(check_framed_concerns(&ws).is_empty());
+        assert!(check_unresolved_references(&ws).is_empty());
+    }
+
+    #[test]
+    fn flags_a_requirement_framing_something_that_isn_t_a_concern() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("NotAConcern"), SymbolKind::PartDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("R1"), SymbolKind::RequirementDefinition, file, span));
+        ws.record_frame(QualifiedName::new("R1"), QualifiedName::new("NotAConcern"));
+
+        let diagnostics = check_framed_concerns(&ws);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, FRAME_TARGET_NOT_A_CONCERN);
+    }
+
+    #[test]
+    fn flags_a_requirement_framing_an_unresolved_concern() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("R1"), SymbolKind::RequirementDefinition, file, span));
+        ws.record_frame(QualifiedName::new("R1"), QualifiedName::new("MissingConcern"));
+
+        assert!(check_framed_concerns(&ws).is_empty(), "an unresolved target has no kind to check");
+        let diagnostics = check_unresolved_references(&ws);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, UNRESOLVED_REFERENCE);
+    }
+
+    #[test]
+    fn flags_a_package_with_no_members() {
+        let file = PathBuf::from("Empty.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Empty"), SymbolKind::Package, file, span));
+
+        let diagnostics = check_empty_packages(&ws, Severity::Warning);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, EMPTY_PACKAGE);
+    }
+
+    #[test]
+    fn referenced_private_definition_is_not_flagged_but_unreferenced_one_is() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Engine"), SymbolKind::PartDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Gearbox"), SymbolKind::PartDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, file, span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::engine"), QualifiedName::new("Engine"));
+
+        let diagnostics = check_unused_definitions(&ws, Severity::Hint);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, UNUSED_DEFINITION);
+        assert!(diagnostics[0].message.contains("Gearbox"));
+    }
+
+    #[test]
+    fn exported_and_stdlib_definitions_are_never_flagged_as_unused() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("PublicApi"), SymbolKind::PartDefinition, file, span));
+        ws.mark_exported(QualifiedName::new("PublicApi"));
+        ws.insert_symbol(Symbol::new(
+            QualifiedName::new("Integer"),
+            SymbolKind::PartDefinition,
+            PathBuf::from(crate::semantic::stdlib::SCALAR_VALUES_FILE),
+            span,
+        ));
+
+        let diagnostics = check_unused_definitions(&ws, Severity::Hint);
+
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn reference_collector_recomputes_a_single_edited_file_without_losing_another_files_reference_to_the_same_target() {
+        let other_file = PathBuf::from("Gearbox.sysml");
+        let edited_file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Engine"), SymbolKind::PartDefinition, other_file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Gearbox::engine"), SymbolKind::PartUsage, other_file.clone(), span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Gearbox::engine"), QualifiedName::new("Engine"));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, edited_file.clone(), span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::engine"), QualifiedName::new("Engine"));
+
+        let mut collector = ReferenceCollector::build(&ws);
+        assert!(collector.is_referenced(&QualifiedName::new("Engine")));
+
+        // Simulate re-editing `Vehicle.sysml` to drop its reference to `Engine`.
+        collector.remove_file(&ws, &edited_file);
+        ws.remove_file(&edited_file);
+        assert!(collector.is_referenced(&QualifiedName::new("Engine")), "Gearbox.sysml still references Engine");
+
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::gearbox"), SymbolKind::PartUsage, edited_file.clone(), span));
+        collector.add_file(&ws, &edited_file);
+        assert!(collector.is_referenced(&QualifiedName::new("Engine")), "recomputing the edited file must not touch the other file's contribution");
+        assert!(!collector.is_referenced(&QualifiedName::new("Vehicle::gearbox")), "the edited file's new symbol has no typing edge pointing at it");
+
+        // Now drop Gearbox.sysml's reference too: Engine should finally read as unreferenced.
+        collector.remove_file(&ws, &other_file);
+        assert!(!collector.is_referenced(&QualifiedName::new("Engine")));
+    }
+
+    #[test]
+    fn a_top_level_definition_named_like_a_stdlib_primitive_is_flagged_with_related_info() {
+        let file = PathBuf::from("Units.sysml");
+        let span = Span::new(Position::new(0, 9), Position::new(0, 13));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Real"), SymbolKind::PartDefinition, file, span));
+
+        let diagnostics = check_stdlib_shadowing(&ws, Severity::Warning);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, STDLIB_SHADOWING);
+        assert_eq!(diagnostics[0].related.len(), 1);
+        assert_eq!(diagnostics[0].related[0].file, PathBuf::from(crate::semantic::stdlib::SCALAR_VALUES_FILE));
+    }
+
+    #[test]
+    fn a_namespaced_definition_with_a_stdlib_like_simple_name_is_not_flagged() {
+        let file = PathBuf::from("Units.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Units::Real"), SymbolKind::PartDefinition, file, span));
+
+        let diagnostics = check_stdlib_shadowing(&ws, Severity::Warning);
+
+        assert!(diagnostics.is_empty(), "a qualified path is an intentional distinct declaration, not a shadow");
+    }
+
+    #[test]
+    fn flags_a_specialization_cycle() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("A"), SymbolKind::PartDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("B"), SymbolKind::PartDefinition, file, span));
+        ws.relationships.add_edge(RelationshipKind::Specialization, QualifiedName::new("A"), QualifiedName::new("B"));
+        ws.relationships.add_edge(RelationshipKind::Specialization, QualifiedName::new("B"), QualifiedName::new("A"));
+
+        let diagnostics = check_specialization_cycles(&ws);
+
+        assert_eq!(diagnostics.len(), 2, "both members of the cycle should be flagged");
+        assert!(diagnostics.iter().all(|d| d.code == SPECIALIZATION_CYCLE));
+    }
+
+    #[test]
+    fn a_single_redefinition_of_an_inherited_feature_is_not_flagged() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Car::wheels"), SymbolKind::AttributeUsage, file, span));
+        ws.relationships.add_edge(RelationshipKind::Redefinition, QualifiedName::new("Car::wheels"), QualifiedName::new("Vehicle::wheels"));
+
+        let diagnostics = check_conflicting_redefinitions(&ws);
+
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn two_members_redefining_the_same_inherited_feature_is_flagged_with_both_offenders() {
+        let file = PathBuf::from("Car.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Car::frontWheels"), SymbolKind::AttributeUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Car::rearWheels"), SymbolKind::AttributeUsage, file, span));
+        ws.relationships.add_edge(RelationshipKind::Redefinition, QualifiedName::new("Car::frontWheels"), QualifiedName::new("Vehicle::wheels"));
+        ws.relationships.add_edge(RelationshipKind::Redefinition, QualifiedName::new("Car::rearWheels"), QualifiedName::new("Vehicle::wheels"));
+
+        let diagnostics = check_conflicting_redefinitions(&ws);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, CONFLICTING_REDEFINITION);
+        assert_eq!(diagnostics[0].related.len(), 1, "the other offender should be attached as related information");
+    }
+
+    #[test]
+    fn flags_a_reference_to_a_name_that_resolves_nowhere() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, file, span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::engine"), QualifiedName::new("Engien"));
+
+        let diagnostics = check_unresolved_references(&ws);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, UNRESOLVED_REFERENCE);
+        assert!(diagnostics[0].message.contains("Engien"));
+    }
+
+    #[test]
+    fn a_reference_to_a_stdlib_primitive_is_not_flagged_as_unresolved() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::mass"), SymbolKind::AttributeUsage, file, span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::mass"), QualifiedName::new("Real"));
+
+        assert!(check_unresolved_references(&ws).is_empty());
+    }
+
+    #[test]
+    fn a_semantic_error_overlapping_a_parse_error_is_suppressed_in_favor_of_the_parse_error() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, file.clone(), span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::engine"), QualifiedName::new("Engien"));
+        ws.set_parse_errors(file, vec![crate::parser::RecoveredError::new("unexpected token 'Engien'", span)]);
+
+        let report = run_validation_suite(&ws);
+
+        assert_eq!(report.diagnostics.len(), 1);
+        assert_eq!(report.diagnostics[0].code, PARSE_ERROR);
+    }
+
+    #[test]
+    fn syntax_only_mode_reports_parse_errors_but_no_semantic_diagnostics() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let usage_span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let error_span = Span::new(Position::new(5, 0), Position::new(5, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, file.clone(), usage_span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::engine"), QualifiedName::new("Engien"));
+        ws.set_parse_errors(file.clone(), vec![crate::parser::RecoveredError::new("unexpected token", error_span)]);
+
+        let report = run_analysis(&ws, AnalysisMode::SyntaxOnly);
+
+        assert_eq!(report.diagnostics.len(), 1);
+        assert_eq!(report.diagnostics[0].code, PARSE_ERROR);
+
+        let full = run_analysis(&ws, AnalysisMode::Full);
+        assert!(full.diagnostics.iter().any(|d| d.code == UNRESOLVED_REFERENCE));
+    }
+
+    #[test]
+    fn scoping_to_one_root_package_excludes_diagnostics_from_an_unrelated_package() {
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let root_file = PathBuf::from("RootA.sysml");
+        let unrelated_file = PathBuf::from("Unrelated.sysml");
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("RootA"), SymbolKind::Package, root_file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("RootA::engine"), SymbolKind::PartUsage, root_file.clone(), span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("RootA::engine"), QualifiedName::new("Engien"));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Unrelated"), SymbolKind::Package, unrelated_file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Unrelated::part"), SymbolKind::PartUsage, unrelated_file, span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Unrelated::part"), QualifiedName::new("Unknownn"));
+
+        let unscoped = run_validation_suite(&ws);
+        assert_eq!(unscoped.diagnostics.len(), 2);
+
+        let scoped = run_validation_suite_scoped(&ws, &[QualifiedName::new("RootA")]);
+
+        assert_eq!(scoped.diagnostics.len(), 1);
+        assert_eq!(scoped.diagnostics[0].file, root_file);
+    }
+
+    #[test]
+    fn the_validation_suite_groups_findings_by_code_with_counts_and_reports_errors() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("A"), SymbolKind::PartDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("B"), SymbolKind::PartDefinition, file.clone(), span));
+        ws.relationships.add_edge(RelationshipKind::Specialization, QualifiedName::new("A"), QualifiedName::new("B"));
+        ws.relationships.add_edge(RelationshipKind::Specialization, QualifiedName::new("B"), QualifiedName::new("A"));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, file, span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::engine"), QualifiedName::new("Engien"));
+
+        let report = run_validation_suite(&ws);
+
+        assert!(report.has_errors());
+        let summary = report.summary();
+        assert!(summary.iter().any(|s| s.code == SPECIALIZATION_CYCLE && s.count == 2));
+        assert!(summary.iter().any(|s| s.code == UNRESOLVED_REFERENCE && s.count == 1));
+    }
+
+    #[test]
+    fn explain_describes_every_known_code_and_rejects_an_unknown_one() {
+        for code in [
+            TYPED_BY_USAGE,
+            FRAME_TARGET_NOT_A_CONCERN,
+            MISMATCHED_END_MULTIPLICITY,
+            MISMATCHED_END_CONJUGATION,
+            EMPTY_PACKAGE,
+            UNUSED_DEFINITION,
+            STDLIB_SHADOWING,
+            SPECIALIZATION_CYCLE,
+            UNRESOLVED_REFERENCE,
+            UNRESOLVED_SUCCESSION_ENDPOINT,
+            UNRESOLVED_RESULT_EXPRESSION,
+            CONFLICTING_REDEFINITION,
+            PARSE_ERROR,
+        ] {
+            assert!(explain(code).is_some_and(|text| !text.is_empty()), "{code} should have a non-empty explanation");
+        }
+
+        assert!(explain("no-such-rule").is_none());
+    }
+
+    #[test]
+    fn flags_a_result_expression_that_cannot_be_resolved() {
+        let file = PathBuf::from("Increment.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Increment::resultExpr"), SymbolKind::AttributeUsage, file, span));
+        ws.relationships.add_edge(RelationshipKind::ResultExpression, QualifiedName::new("Increment::resultExpr"), QualifiedName::new("Increment::missing"));
+
+        let diagnostics = check_unresolved_references(&ws);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, UNRESOLVED_REFERENCE);
+        assert!(diagnostics[0].message.contains("missing"));
+    }
+}
@@ -0,0 +1,126 @@
+//! A minimal diagnostics-only entry point for lightweight integrations
+//! (editors without full LSP support) that want to check a single string
+//! without constructing and populating a whole [`Workspace`].
+//!
+//! There's no full grammar wired into this crate yet (see
+//! [`crate::parser::recovery`]), so [`analyze_str`] only understands the one
+//! construct common enough to be worth a minimal scanner today: a single
+//! `name : Type;` typed declaration. Once a real per-language parser lands,
+//! it dispatches through here instead of growing its own entry point.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::diagnostics::{check_unresolved_references, Diagnostic};
+use crate::semantic::qualified_name::QualifiedName;
+use crate::semantic::relationship_graph::RelationshipKind;
+use crate::semantic::symbol::{Symbol, SymbolKind};
+use crate::span::{Position, Span};
+use crate::syntax::Language;
+use crate::workspace::Workspace;
+
+/// A cheaply-cloned, `Arc`-backed handle to a set of standard library names
+/// considered resolvable, so repeated [`analyze_str`] calls over many small
+/// strings don't pay to rebuild the set each time.
+#[derive(Debug, Clone, Default)]
+pub struct StdlibHandle {
+    names: Arc<std::collections::HashSet<QualifiedName>>,
+}
+
+impl StdlibHandle {
+    pub fn new(names: impl IntoIterator<Item = QualifiedName>) -> Self {
+        Self { names: Arc::new(names.into_iter().collect()) }
+    }
+
+    pub fn resolves(&self, name: &QualifiedName) -> bool {
+        self.names.contains(name)
+    }
+}
+
+/// Options for [`analyze_str`].
+#[derive(Debug, Clone, Default)]
+pub struct AnalyzeOptions {
+    /// A shared stdlib handle, checked alongside the built-in primitive
+    /// types ([`crate::semantic::stdlib::lookup_primitive`]) before a
+    /// reference is flagged as unresolved.
+    pub stdlib: Option<StdlibHandle>,
+}
+
+/// The virtual file diagnostics from [`analyze_str`] are attributed to,
+/// since the caller's string has no path of its own.
+fn virtual_file(kind: Language) -> PathBuf {
+    match kind {
+        Language::SysML => PathBuf::from("<string>.sysml"),
+        Language::KerML => PathBuf::from("<string>.kerml"),
+    }
+}
+
+/// Scans `content` for the first `name : Type;`-shaped declaration — the
+/// only construct understood without a real grammar — returning the
+/// declared name and its type reference.
+fn scan_single_typed_declaration(content: &str) -> Option<(String, String)> {
+    for line in content.lines() {
+        let line = line.trim().trim_end_matches(';');
+        let Some((before, ty)) = line.split_once(':') else { continue };
+        let name = before.trim().rsplit(' ').next()?.trim();
+        let ty = ty.trim();
+        if !name.is_empty() && !ty.is_empty() {
+            return Some((name.to_string(), ty.to_string()));
+        }
+    }
+    None
+}
+
+/// Parses the single declaration `content` contains (see
+/// [`scan_single_typed_declaration`]) and returns any diagnostics it
+/// produces — today, just an unresolved type reference — without the
+/// caller needing to construct a full [`Workspace`]. Pass a shared
+/// [`StdlibHandle`] via `opts` to avoid treating every stdlib type as
+/// unresolved on every call.
+pub fn analyze_str(content: &str, kind: Language, opts: &AnalyzeOptions) -> Vec<Diagnostic> {
+    let Some((name, ty)) = scan_single_typed_declaration(content) else { return Vec::new() };
+
+    let file = virtual_file(kind);
+    let name = QualifiedName::new(name);
+    let ty = QualifiedName::new(ty);
+    let span = Span::new(Position::new(0, 0), Position::new(0, name.to_string().len() as u32));
+
+    let mut workspace = Workspace::default();
+    workspace.insert_symbol(Symbol::new(name.clone(), SymbolKind::AttributeUsage, file.clone(), span));
+    workspace.relationships.add_edge(RelationshipKind::Typing, name, ty.clone());
+
+    if opts.stdlib.as_ref().is_some_and(|stdlib| stdlib.resolves(&ty)) {
+        workspace.insert_symbol(Symbol::new(ty, SymbolKind::PartDefinition, file, Span::new(Position::new(0, 0), Position::new(0, 1))));
+    }
+
+    check_unresolved_references(&workspace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn an_unresolved_type_reference_is_reported_without_a_stdlib_handle() {
+        let diagnostics = analyze_str("attribute mass : Mss;", Language::SysML, &AnalyzeOptions::default());
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, crate::diagnostics::UNRESOLVED_REFERENCE);
+        assert!(diagnostics[0].message.contains("Mss"));
+    }
+
+    #[test]
+    fn a_type_reference_resolved_by_a_shared_stdlib_handle_is_not_flagged() {
+        let stdlib = StdlibHandle::new([QualifiedName::new("Mass")]);
+        let opts = AnalyzeOptions { stdlib: Some(stdlib) };
+
+        let diagnostics = analyze_str("attribute mass : Mass;", Language::SysML, &opts);
+
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn content_with_no_recognizable_declaration_yields_no_diagnostics() {
+        assert!(analyze_str("package Vehicle { }", Language::SysML, &AnalyzeOptions::default()).is_empty());
+    }
+}
@@ -0,0 +1,165 @@
+//! Unified-diff rendering between two versions of a file's text, used by
+//! `syster fmt --diff` so review tooling and pre-commit hooks can see what
+//! formatting would change without it being applied.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Renders a unified diff (`--- a\n+++ b\n@@ ... @@\n...`) between
+/// `original` and `modified`, with `context` lines of unchanged context
+/// around each run of changes. Returns an empty string if the two are
+/// identical.
+pub fn unified_diff(original: &str, modified: &str, context: usize) -> String {
+    let old_lines: Vec<&str> = original.lines().collect();
+    let new_lines: Vec<&str> = modified.lines().collect();
+    let ops = diff_ops(&old_lines, &new_lines);
+    if ops.iter().all(|(op, _, _)| *op == Op::Equal) {
+        return String::new();
+    }
+
+    let mut out = String::new();
+    out.push_str("--- original\n");
+    out.push_str("+++ formatted\n");
+
+    for hunk in hunks(&ops, context) {
+        let (old_start, old_len) = hunk.old_range;
+        let (new_start, new_len) = hunk.new_range;
+        out.push_str(&format!("@@ -{},{} +{},{} @@\n", old_start, old_len, new_start, new_len));
+        for &idx in &hunk.op_indices {
+            let (op, old_line, new_line) = ops[idx];
+            match op {
+                Op::Equal => out.push_str(&format!(" {}\n", old_line.unwrap())),
+                Op::Delete => out.push_str(&format!("-{}\n", old_line.unwrap())),
+                Op::Insert => out.push_str(&format!("+{}\n", new_line.unwrap())),
+            }
+        }
+    }
+
+    out
+}
+
+/// Longest-common-subsequence diff between `old` and `new`, expressed as a
+/// flat list of `(op, old_line, new_line)` triples in output order.
+fn diff_ops<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<(Op, Option<&'a str>, Option<&'a str>)> {
+    let (m, n) = (old.len(), new.len());
+    let mut lcs = vec![vec![0usize; n + 1]; m + 1];
+    for i in (0..m).rev() {
+        for j in (0..n).rev() {
+            lcs[i][j] = if old[i] == new[j] { lcs[i + 1][j + 1] + 1 } else { lcs[i + 1][j].max(lcs[i][j + 1]) };
+        }
+    }
+
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < m && j < n {
+        if old[i] == new[j] {
+            ops.push((Op::Equal, Some(old[i]), Some(new[j])));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            ops.push((Op::Delete, Some(old[i]), None));
+            i += 1;
+        } else {
+            ops.push((Op::Insert, None, Some(new[j])));
+            j += 1;
+        }
+    }
+    while i < m {
+        ops.push((Op::Delete, Some(old[i]), None));
+        i += 1;
+    }
+    while j < n {
+        ops.push((Op::Insert, None, Some(new[j])));
+        j += 1;
+    }
+    ops
+}
+
+struct Hunk {
+    old_range: (usize, usize),
+    new_range: (usize, usize),
+    op_indices: Vec<usize>,
+}
+
+/// Groups `ops` into hunks, merging change runs that are within
+/// `2 * context` lines of each other, the same way `diff -U` does.
+fn hunks(ops: &[(Op, Option<&str>, Option<&str>)], context: usize) -> Vec<Hunk> {
+    let changed: Vec<usize> = ops.iter().enumerate().filter(|(_, (op, _, _))| *op != Op::Equal).map(|(idx, _)| idx).collect();
+    if changed.is_empty() {
+        return Vec::new();
+    }
+
+    let mut groups: Vec<(usize, usize)> = Vec::new();
+    let (mut start, mut end) = (changed[0], changed[0]);
+    for &idx in &changed[1..] {
+        if idx.saturating_sub(end) <= context * 2 {
+            end = idx;
+        } else {
+            groups.push((start, end));
+            start = idx;
+            end = idx;
+        }
+    }
+    groups.push((start, end));
+
+    groups
+        .into_iter()
+        .map(|(start, end)| {
+            let lo = start.saturating_sub(context);
+            let hi = (end + context).min(ops.len().saturating_sub(1));
+            let op_indices: Vec<usize> = (lo..=hi).collect();
+
+            let mut old_line = ops[..lo].iter().filter(|(op, _, _)| *op != Op::Insert).count() + 1;
+            let mut new_line = ops[..lo].iter().filter(|(op, _, _)| *op != Op::Delete).count() + 1;
+            let old_start = old_line;
+            let new_start = new_line;
+            let mut old_len = 0;
+            let mut new_len = 0;
+            for &idx in &op_indices {
+                match ops[idx].0 {
+                    Op::Equal => {
+                        old_len += 1;
+                        new_len += 1;
+                        old_line += 1;
+                        new_line += 1;
+                    }
+                    Op::Delete => {
+                        old_len += 1;
+                        old_line += 1;
+                    }
+                    Op::Insert => {
+                        new_len += 1;
+                        new_line += 1;
+                    }
+                }
+            }
+
+            Hunk { old_range: (old_start, old_len), new_range: (new_start, new_len), op_indices }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_text_produces_an_empty_diff() {
+        assert_eq!(unified_diff("a\nb\nc", "a\nb\nc", 3), "");
+    }
+
+    #[test]
+    fn a_changed_line_is_rendered_with_context() {
+        let diff = unified_diff("a\nb\nc", "a\nx\nc", 1);
+
+        assert!(diff.starts_with("--- original\n+++ formatted\n"));
+        assert!(diff.contains("-b\n"));
+        assert!(diff.contains("+x\n"));
+        assert!(diff.contains(" a\n"));
+        assert!(diff.contains(" c\n"));
+    }
+}
@@ -0,0 +1,98 @@
+//! Minimal `.editorconfig` support: just the keys this formatter cares
+//! about (`indent_style`, `indent_size`/`tab_width`, `max_line_length`),
+//! ignoring every other key (charset, trim_trailing_whitespace, etc.).
+//! Section headers are not matched against file names: the keys of every
+//! section apply, `[*.md]` as much as `[*]`.
+
+use std::path::Path;
+
+use super::options::{FormatOptions, IndentUnit};
+
+impl FormatOptions {
+    /// Parses the relevant keys out of `.editorconfig` file content.
+    /// Section headers (`[*]`, `[*.sysml]`, ...) are skipped rather than
+    /// matched against a glob — team `.editorconfig` files nearly always
+    /// apply one style to every file, so the extra matching isn't worth
+    /// the complexity yet.
+    pub fn from_editorconfig_str(content: &str) -> Self {
+        let mut options = Self::default();
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') || line.starts_with('[') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else { continue };
+            let (key, value) = (key.trim(), value.trim());
+            match key {
+                "indent_style" => {
+                    options.indent_unit = if value == "tab" { IndentUnit::Tabs } else { IndentUnit::Spaces };
+                }
+                "indent_size" | "tab_width" => {
+                    if let Ok(width) = value.parse() {
+                        options.indent_width = width;
+                    }
+                }
+                "max_line_length" => {
+                    if let Ok(width) = value.parse() {
+                        options.max_line_width = width;
+                    }
+                }
+                _ => {}
+            }
+        }
+        options
+    }
+
+    /// Walks upward from `start_dir` looking for the nearest
+    /// `.editorconfig`, returning [`FormatOptions::default`] if none is
+    /// found (or it can't be read).
+    pub fn from_nearest_editorconfig(start_dir: &Path) -> Self {
+        let mut dir = Some(start_dir);
+        while let Some(d) = dir {
+            if let Ok(content) = std::fs::read_to_string(d.join(".editorconfig")) {
+                return Self::from_editorconfig_str(&content);
+            }
+            dir = d.parent();
+        }
+        Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tabs_in_editorconfig_make_the_formatter_emit_tab_indentation() {
+        let options = FormatOptions::from_editorconfig_str("[*]\nindent_style = tab\nindent_size = 2\n");
+        assert_eq!(options.indent_unit, IndentUnit::Tabs);
+        assert_eq!(options.indent_width, 2);
+
+        let formatted = crate::format::normalize_indentation("part def Vehicle {\n  part engine;\n}", &options);
+        assert!(formatted.contains("\n\tpart engine;\n"));
+    }
+
+    #[test]
+    fn missing_editorconfig_falls_back_to_defaults() {
+        let dir = std::env::temp_dir().join("syster-editorconfig-missing-test");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let options = FormatOptions::from_nearest_editorconfig(&dir);
+
+        assert_eq!(options, FormatOptions::default());
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn finds_editorconfig_in_a_parent_directory() {
+        let root = std::env::temp_dir().join(format!("syster-editorconfig-nested-test-{:?}", std::thread::current().id()));
+        let nested = root.join("a/b");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(root.join(".editorconfig"), "max_line_length = 80\n").unwrap();
+
+        let options = FormatOptions::from_nearest_editorconfig(&nested);
+
+        assert_eq!(options.max_line_width, 80);
+        std::fs::remove_dir_all(&root).ok();
+    }
+}
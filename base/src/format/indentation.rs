@@ -0,0 +1,99 @@
+//! Leading-indentation normalization, run as a pre-pass before the rest of
+//! the formatter so later passes never have to reason about mixed
+//! tabs/spaces.
+
+use super::options::{FormatOptions, IndentUnit};
+
+/// Rewrites every line's leading indentation to `options.indent_unit`,
+/// regardless of whether the source mixed tabs and spaces.
+///
+/// Lines inside a `/* ... */` block comment (other than the one opening it)
+/// are left untouched, since their whitespace is often meaningful alignment
+/// relative to the comment's opening `/*` rather than a nesting level.
+pub fn normalize_indentation(source: &str, options: &FormatOptions) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut in_block_comment = false;
+
+    for (i, line) in source.lines().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+
+        if in_block_comment {
+            out.push_str(line);
+        } else {
+            let (leading, rest) = split_leading_whitespace(line);
+            let level = indent_level(leading, options.indent_width);
+            out.push_str(&render_indent(level, options));
+            out.push_str(rest);
+        }
+
+        if block_comment_opens_without_closing(line) {
+            in_block_comment = true;
+        } else if in_block_comment && line.contains("*/") {
+            in_block_comment = false;
+        }
+    }
+
+    out
+}
+
+fn split_leading_whitespace(line: &str) -> (&str, &str) {
+    let end = line.find(|c: char| c != ' ' && c != '\t').unwrap_or(line.len());
+    line.split_at(end)
+}
+
+/// Expands tabs to `indent_width` columns, then rounds to the nearest whole
+/// indent level.
+fn indent_level(leading: &str, indent_width: usize) -> usize {
+    if indent_width == 0 {
+        return 0;
+    }
+    let columns: usize = leading
+        .chars()
+        .map(|c| if c == '\t' { indent_width } else { 1 })
+        .sum();
+    (columns + indent_width / 2) / indent_width
+}
+
+fn render_indent(level: usize, options: &FormatOptions) -> String {
+    match options.indent_unit {
+        IndentUnit::Spaces => " ".repeat(level * options.indent_width),
+        IndentUnit::Tabs => "\t".repeat(level),
+    }
+}
+
+fn block_comment_opens_without_closing(line: &str) -> bool {
+    match line.rfind("/*") {
+        Some(open_idx) => !line[open_idx..].contains("*/"),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_mixed_tabs_and_spaces_idempotently() {
+        let source = "part def Vehicle {\n\tpart engine : Engine;\n  port p : Power;\n\t  attribute mass;\n}";
+        let options = FormatOptions { indent_unit: IndentUnit::Spaces, indent_width: 4, ..FormatOptions::default() };
+
+        let once = normalize_indentation(source, &options);
+        let twice = normalize_indentation(&once, &options);
+
+        assert_eq!(once, twice, "normalization must be idempotent");
+        assert!(once.contains("\n    part engine"));
+        assert!(once.contains("\n    port p"));
+    }
+
+    #[test]
+    fn preserves_alignment_inside_block_comments() {
+        let source = "/* diagram:\n     +---+\n     | A |\n     +---+\n*/\npart def A;";
+        let options = FormatOptions::default();
+
+        let formatted = normalize_indentation(source, &options);
+
+        assert!(formatted.contains("\n     +---+\n     | A |\n     +---+\n"));
+    }
+}
@@ -0,0 +1,19 @@
+//! Line-based formatter for SysML v2 / KerML source.
+
+pub mod diff;
+pub mod editorconfig;
+pub mod indentation;
+pub mod options;
+pub mod wrapping;
+
+pub use diff::unified_diff;
+pub use indentation::normalize_indentation;
+pub use options::{FormatOptions, IndentUnit};
+pub use wrapping::wrap_long_lists;
+
+/// Runs the full formatting pipeline (indentation normalization, then
+/// long-list wrapping) in one call, for callers that don't need the
+/// individual passes.
+pub fn format_str(source: &str, options: &FormatOptions) -> String {
+    wrap_long_lists(&normalize_indentation(source, options), options)
+}
@@ -0,0 +1,23 @@
+//! Formatter configuration.
+
+/// The whitespace character used for one level of indentation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndentUnit {
+    Spaces,
+    Tabs,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatOptions {
+    pub indent_unit: IndentUnit,
+    /// Number of spaces in one indent level (also used to expand tabs when
+    /// measuring existing indentation).
+    pub indent_width: usize,
+    pub max_line_width: usize,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self { indent_unit: IndentUnit::Spaces, indent_width: 4, max_line_width: 100 }
+    }
+}
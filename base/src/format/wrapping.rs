@@ -0,0 +1,128 @@
+//! Wraps long comma-separated specialization/import/argument lists onto
+//! continuation lines, run after [`normalize_indentation`](super::normalize_indentation)
+//! so continuation lines inherit the same indent unit/width.
+
+use super::options::{FormatOptions, IndentUnit};
+
+/// Rewrites lines longer than `options.max_line_width` that introduce a
+/// comma-separated specialization list (`:> A, B, C`), import list, or
+/// parenthesized argument list, breaking the list onto one item per
+/// continuation line. Lines at or under the threshold are left untouched,
+/// and lines with no recognizable list are left untouched too, since
+/// wrapping arbitrary prose would only make it harder to read.
+pub fn wrap_long_lists(source: &str, options: &FormatOptions) -> String {
+    let mut out = String::with_capacity(source.len());
+    for (i, line) in source.lines().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        out.push_str(&wrap_line(line, options));
+    }
+    out
+}
+
+fn wrap_line(line: &str, options: &FormatOptions) -> String {
+    if line.chars().count() <= options.max_line_width {
+        return line.to_string();
+    }
+
+    let Some((prefix, list, suffix)) = split_list(line) else { return line.to_string() };
+    let items: Vec<&str> = list.split(',').map(str::trim).filter(|item| !item.is_empty()).collect();
+    if items.len() < 2 {
+        return line.to_string();
+    }
+
+    let indent = line.chars().take_while(|c| *c == ' ' || *c == '\t').count();
+    let continuation = continuation_indent(indent, options);
+
+    let mut wrapped = String::new();
+    wrapped.push_str(prefix.trim_end());
+    wrapped.push('\n');
+    for (idx, item) in items.iter().enumerate() {
+        wrapped.push_str(&continuation);
+        wrapped.push_str(item);
+        if idx + 1 < items.len() {
+            wrapped.push(',');
+            wrapped.push('\n');
+        } else {
+            wrapped.push_str(suffix);
+        }
+    }
+    wrapped
+}
+
+/// Splits a line into `(prefix ending in the list-introducing marker, the
+/// comma-separated list itself, trailing suffix such as `;` or `{`)`, for
+/// the list shapes this pass knows how to wrap.
+fn split_list(line: &str) -> Option<(&str, &str, &str)> {
+    for marker in [":> ", "import "] {
+        if let Some(marker_idx) = line.find(marker) {
+            let list_start = marker_idx + marker.len();
+            let (list, suffix) = split_trailing_punctuation(&line[list_start..]);
+            return Some((&line[..list_start], list, suffix));
+        }
+    }
+
+    let open = line.find('(')?;
+    let close = line.rfind(')')?;
+    if close <= open {
+        return None;
+    }
+    Some((&line[..open + 1], &line[open + 1..close], &line[close..]))
+}
+
+/// Peels a trailing `;` or `{` (and any whitespace before it) off `text`,
+/// returning `(list, suffix)`.
+fn split_trailing_punctuation(text: &str) -> (&str, &str) {
+    let trimmed = text.trim_end();
+    for suffix in [";", "{"] {
+        if let Some(list) = trimmed.strip_suffix(suffix) {
+            return (list.trim_end(), suffix);
+        }
+    }
+    (trimmed, "")
+}
+
+fn continuation_indent(base_columns: usize, options: &FormatOptions) -> String {
+    match options.indent_unit {
+        IndentUnit::Spaces => " ".repeat(base_columns + options.indent_width),
+        IndentUnit::Tabs => "\t".repeat(base_columns / options.indent_width.max(1) + 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_short_specialization_list_stays_on_one_line() {
+        let options = FormatOptions { max_line_width: 80, ..FormatOptions::default() };
+        let source = "part def Car :> Vehicle, Machine;";
+
+        assert_eq!(wrap_long_lists(source, &options), source);
+    }
+
+    #[test]
+    fn a_long_specialization_list_wraps_one_item_per_line() {
+        let options = FormatOptions { max_line_width: 40, ..FormatOptions::default() };
+        let source = "part def Car :> Vehicle, Machine, Thing, NamedElement;";
+
+        let wrapped = wrap_long_lists(source, &options);
+
+        assert_eq!(
+            wrapped,
+            "part def Car :>\n    Vehicle,\n    Machine,\n    Thing,\n    NamedElement;"
+        );
+    }
+
+    #[test]
+    fn wrapping_a_long_list_is_idempotent() {
+        let options = FormatOptions { max_line_width: 40, ..FormatOptions::default() };
+        let source = "part def Car :> Vehicle, Machine, Thing, NamedElement;";
+
+        let once = wrap_long_lists(source, &options);
+        let twice = wrap_long_lists(&once, &options);
+
+        assert_eq!(once, twice, "wrapping must be idempotent");
+    }
+}
@@ -0,0 +1,36 @@
+//! Core parser, AST, and semantic analysis for SysML v2 and KerML.
+//!
+//! `parser`, `ast`, `format`, and `span` have no dependency on the
+//! cross-file resolution stack and stay available with default features
+//! off, for embedders that just want the grammar/AST (e.g. a syntax
+//! highlighter) without pulling in symbol tables, the relationship graph,
+//! or project-manifest parsing. That heavier stack lives behind the
+//! `semantic` feature (on by default). `persist` (implies `semantic`)
+//! additionally derives `serde::{Serialize, Deserialize}` on the symbol
+//! table, relationship graph, and side tables, so a [`Workspace`] can be
+//! written to and read back from a bincode-encoded index file — see
+//! [`Workspace::save_index`]/[`Workspace::load_index`].
+
+pub mod ast;
+#[cfg(feature = "semantic")]
+pub mod diagnostics;
+#[cfg(feature = "semantic")]
+pub mod embed;
+pub mod format;
+pub mod parser;
+#[cfg(feature = "semantic")]
+pub mod project;
+#[cfg(feature = "semantic")]
+pub mod semantic;
+pub mod span;
+pub mod syntax;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "semantic")]
+pub mod workspace;
+
+pub use span::{format_span, span_to_line_col, Position, Span};
+#[cfg(feature = "semantic")]
+pub use embed::{analyze_str, AnalyzeOptions, StdlibHandle};
+#[cfg(feature = "semantic")]
+pub use workspace::{DocumentSymbolOptions, OutlineEntry, Workspace, WorkspaceBuilder, WorkspaceSnapshot, WorkspaceStatistics};
@@ -0,0 +1,6 @@
+//! Parsing support — syntax only, no cross-file or semantic knowledge. No
+//! Pest grammar is wired in yet (see [`recovery`]).
+
+pub mod recovery;
+
+pub use recovery::{scan_unterminated_delimiters, ParseMode, ParseOutcome, RecoveredError};
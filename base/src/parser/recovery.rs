@@ -0,0 +1,188 @@
+//! Error-recovery results from a parse: a syntax error doesn't stop
+//! the whole file from parsing, but the recovered gaps need to surface
+//! somewhere (the LSP publishes them as diagnostics).
+//!
+//! No Pest grammar or lexer is wired into this tree yet: this module holds
+//! the result types a parse entry point reports through, plus
+//! [`scan_unterminated_delimiters`], a standalone character scan.
+
+use crate::span::{Position, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecoveredError {
+    pub message: String,
+    pub span: Span,
+}
+
+impl RecoveredError {
+    pub fn new(message: impl Into<String>, span: Span) -> Self {
+        Self { message: message.into(), span }
+    }
+}
+
+/// Whether a parse should recover from a syntax error and keep going
+/// ([`Lenient`](ParseMode::Lenient), the default) or reject the whole file
+/// the moment one is found ([`Strict`](ParseMode::Strict)), matching the
+/// pre-recovery behavior for authoring pipelines that must not accept any
+/// malformed input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    #[default]
+    Lenient,
+    Strict,
+}
+
+/// The outcome of parsing one file: the errors [`RecoveredError`]s found,
+/// plus whatever partial-or-complete result `content` a given parse entry
+/// point produces — `None` in [`ParseMode::Strict`] as soon as any error
+/// was found, since that mode never keeps a partial result around.
+///
+/// A generic wrapper rather than a concrete AST type, so every parse entry
+/// point (file-based, string-based) can apply the same strict/lenient rule
+/// in one place instead of duplicating the `if strict && has_errors` check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOutcome<T> {
+    pub content: Option<T>,
+    pub errors: Vec<RecoveredError>,
+}
+
+impl<T> ParseOutcome<T> {
+    pub fn new(content: T, errors: Vec<RecoveredError>, mode: ParseMode) -> Self {
+        if mode == ParseMode::Strict && !errors.is_empty() {
+            Self { content: None, errors }
+        } else {
+            Self { content: Some(content), errors }
+        }
+    }
+}
+
+/// Scans `content` for a `/* ...` block comment or `"..."` string literal
+/// opened but never closed by end of file, reporting a [`RecoveredError`]
+/// at the opening delimiter rather than letting the unterminated run
+/// consume the rest of the file silently. There's no real lexer in this
+/// tree yet (see the module docs above), so this is a standalone character
+/// scan rather than a recovery path wired into a Pest grammar; once a real
+/// lexer lands, it should detect this case itself and this function goes
+/// away.
+pub fn scan_unterminated_delimiters(content: &str) -> Vec<RecoveredError> {
+    #[derive(Clone, Copy)]
+    enum State {
+        Normal,
+        BlockComment(Position),
+        StringLiteral(Position),
+    }
+
+    let mut state = State::Normal;
+    let mut line = 0u32;
+    let mut column = 0u32;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let here = Position::new(line, column);
+        match state {
+            State::Normal => {
+                if c == '/' && chars.peek() == Some(&'*') {
+                    chars.next();
+                    column += 1;
+                    state = State::BlockComment(here);
+                } else if c == '"' {
+                    state = State::StringLiteral(here);
+                }
+            }
+            State::BlockComment(_) => {
+                if c == '*' && chars.peek() == Some(&'/') {
+                    chars.next();
+                    column += 1;
+                    state = State::Normal;
+                }
+            }
+            State::StringLiteral(_) => {
+                if c == '\\' {
+                    chars.next();
+                    column += 1;
+                } else if c == '"' {
+                    state = State::Normal;
+                }
+            }
+        }
+        if c == '\n' {
+            line += 1;
+            column = 0;
+        } else {
+            column += 1;
+        }
+    }
+
+    let end = Position::new(line, column);
+    match state {
+        State::BlockComment(start) => {
+            vec![RecoveredError::new("unterminated block comment", Span::new(start, end))]
+        }
+        State::StringLiteral(start) => {
+            vec![RecoveredError::new("unterminated string literal", Span::new(start, end))]
+        }
+        State::Normal => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_at(line: u32) -> RecoveredError {
+        RecoveredError::new("unexpected token", Span::new(Position::new(line, 0), Position::new(line, 1)))
+    }
+
+    #[test]
+    fn strict_mode_yields_no_content_on_a_single_localized_error() {
+        let outcome = ParseOutcome::new("partial ast", vec![error_at(3)], ParseMode::Strict);
+
+        assert!(outcome.content.is_none());
+        assert_eq!(outcome.errors.len(), 1);
+    }
+
+    #[test]
+    fn lenient_mode_keeps_the_partial_result_alongside_the_same_error() {
+        let outcome = ParseOutcome::new("partial ast", vec![error_at(3)], ParseMode::Lenient);
+
+        assert_eq!(outcome.content, Some("partial ast"));
+        assert_eq!(outcome.errors.len(), 1);
+    }
+
+    #[test]
+    fn strict_mode_keeps_the_result_when_there_are_no_errors_at_all() {
+        let outcome = ParseOutcome::new("clean ast", Vec::new(), ParseMode::Strict);
+
+        assert_eq!(outcome.content, Some("clean ast"));
+    }
+
+    #[test]
+    fn an_unterminated_block_comment_is_reported_at_its_opening_delimiter() {
+        let content = "part def Vehicle;\n/* this comment never closes\npart def Engine;";
+
+        let errors = scan_unterminated_delimiters(content);
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message, "unterminated block comment");
+        assert_eq!(errors[0].span.start, Position::new(1, 0));
+    }
+
+    #[test]
+    fn an_unterminated_string_literal_is_reported_at_its_opening_quote() {
+        let content = "attribute label default \"never closed\npart def Engine;";
+
+        let errors = scan_unterminated_delimiters(content);
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message, "unterminated string literal");
+        assert_eq!(errors[0].span.start, Position::new(0, 24));
+    }
+
+    #[test]
+    fn a_closed_block_comment_and_string_report_nothing() {
+        let content = "/* fine */ attribute label default \"fine\";";
+
+        assert!(scan_unterminated_delimiters(content).is_empty());
+    }
+}
@@ -0,0 +1,65 @@
+//! `.sysml` project manifests (`syster.toml`), so a workspace root doesn't
+//! need `--include-glob`/`--exclude-glob` repeated on every invocation.
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::workspace::{DirLoadOptions, Workspace};
+
+/// The schema of `syster.toml` at a project's root.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ProjectManifest {
+    #[serde(default)]
+    pub include: Vec<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+impl ProjectManifest {
+    pub const FILE_NAME: &'static str = "syster.toml";
+
+    pub fn parse(contents: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(contents)
+    }
+
+    fn to_load_options(&self) -> DirLoadOptions {
+        DirLoadOptions { include_globs: self.include.clone(), exclude_globs: self.exclude.clone(), ..Default::default() }
+    }
+}
+
+impl Workspace {
+    /// Loads `root`, reading `syster.toml` there (if present) for
+    /// include/exclude globs instead of requiring them on the command
+    /// line every time.
+    pub fn load_project(root: &Path) -> std::io::Result<Workspace> {
+        let manifest_path = root.join(ProjectManifest::FILE_NAME);
+        let options = match std::fs::read_to_string(&manifest_path) {
+            Ok(contents) => ProjectManifest::parse(&contents)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?
+                .to_load_options(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => DirLoadOptions::default(),
+            Err(e) => return Err(e),
+        };
+        Workspace::load_dir_with_options(root, &options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_include_and_exclude_globs_from_toml() {
+        let manifest = ProjectManifest::parse("include = [\"*.sysml\"]\nexclude = [\"sysml.library/**\"]\n").unwrap();
+        assert_eq!(manifest.include, vec!["*.sysml".to_string()]);
+        assert_eq!(manifest.exclude, vec!["sysml.library/**".to_string()]);
+    }
+
+    #[test]
+    fn missing_fields_default_to_empty() {
+        let manifest = ProjectManifest::parse("").unwrap();
+        assert!(manifest.include.is_empty());
+        assert!(manifest.exclude.is_empty());
+    }
+}
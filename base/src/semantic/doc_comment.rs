@@ -0,0 +1,36 @@
+//! Doc comments attached to declarations, surfaced by hover and (on
+//! resolve) completion.
+
+use std::collections::HashMap;
+
+use crate::semantic::qualified_name::QualifiedName;
+
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct DocCommentTable {
+    comments: HashMap<QualifiedName, String>,
+}
+
+impl DocCommentTable {
+    pub fn set(&mut self, name: QualifiedName, doc: impl Into<String>) {
+        self.comments.insert(name, doc.into());
+    }
+
+    pub fn get(&self, name: &QualifiedName) -> Option<&str> {
+        self.comments.get(name).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_the_doc_attached_to_a_name_and_none_for_everything_else() {
+        let mut docs = DocCommentTable::default();
+        docs.set(QualifiedName::new("Vehicle::engine"), "The primary power source.");
+
+        assert_eq!(docs.get(&QualifiedName::new("Vehicle::engine")), Some("The primary power source."));
+        assert_eq!(docs.get(&QualifiedName::new("Vehicle::wheels")), None);
+    }
+}
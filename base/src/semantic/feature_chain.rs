@@ -0,0 +1,435 @@
+//! Resolution of dotted/`::`-free feature-chain references such as
+//! `perform engine.start;`, where each segment after the first names a
+//! nested feature of the previous segment's type rather than a qualified
+//! package path.
+
+use crate::semantic::qualified_name::QualifiedName;
+use crate::semantic::relationship_graph::RelationshipKind;
+use crate::semantic::symbol::Symbol;
+use crate::workspace::Workspace;
+
+impl Workspace {
+    /// Resolves a feature chain like `["engine", "start"]` rooted at
+    /// `scope` (the enclosing part/action), by resolving each segment as a
+    /// nested member of the previous segment's symbol.
+    ///
+    /// Used for `perform <chain>;` action invocations and `bind x = b.y;`
+    /// binding connectors, where `chain` walks through part/part-usage
+    /// segments (e.g. `engine`, `b`) to reach a feature declared either
+    /// directly nested under the previous segment, or — when the previous
+    /// segment redeclares nothing of its own — on the type it's `typed by`
+    /// (e.g. `b.y` resolving `y` on `b`'s type when `b` itself has no
+    /// nested `y`).
+    pub fn resolve_feature_chain(&self, scope: &QualifiedName, segments: &[&str]) -> Option<&Symbol> {
+        let mut current = scope.clone();
+        let mut resolved = None;
+        for segment in segments {
+            let nested = current.join(segment);
+            resolved = self.symbol_by_qualified_name(&nested).or_else(|| self.resolve_on_type(&current, segment));
+            current = resolved?.qualified_name.clone();
+        }
+        resolved
+    }
+
+    /// Falls back to resolving `segment` as a member of `scope`'s declared
+    /// type, for a segment whose previous link has no nested member of its
+    /// own (it inherits everything from its type instead).
+    fn resolve_on_type(&self, scope: &QualifiedName, segment: &str) -> Option<&Symbol> {
+        let ty = self.relationships.edges(RelationshipKind::Typing, scope).first()?;
+        self.symbol_by_qualified_name(&ty.join(segment))
+    }
+
+    /// Resolves each feature chain in `ends` against `scope` (e.g.
+    /// `["l", "component"]` and `["assembly", "element"]` for `allocate
+    /// l.component to assembly.element;`, or more than two for the n-ary
+    /// `allocate ( logical ::> l, physical ::> p )` form) and records an
+    /// [`AllocationEnd`](RelationshipKind::AllocationEnd) edge from
+    /// `allocation` to each one that resolves, so navigation and the
+    /// relationship graph see both ends of the allocation. The `logical
+    /// ::>`/`physical ::>` role label in the n-ary form is just a name for
+    /// the end, not part of the chain itself — the caller passes the
+    /// reference-subsetted target (`l`, `p`) as `chain`, so it resolves to
+    /// its own declaration exactly like a two-ends `allocate`'s feature
+    /// chain, and goto-definition/find-references on it work through the
+    /// same `AllocationEnd` edge without any `::>`-specific handling.
+    pub fn record_allocation(&mut self, allocation: QualifiedName, scope: &QualifiedName, ends: &[&[&str]]) {
+        let targets: Vec<QualifiedName> =
+            ends.iter().filter_map(|chain| self.resolve_feature_chain(scope, chain)).map(|s| s.qualified_name.clone()).collect();
+        for target in targets {
+            self.relationships.add_edge(RelationshipKind::AllocationEnd, allocation.clone(), target);
+        }
+    }
+
+    /// Resolves `source` and `target` feature chains for a `first <source>
+    /// then <target>;` succession rooted at `scope`, recording a
+    /// [`SuccessionEnd`](RelationshipKind::SuccessionEnd) edge from
+    /// `succession` to each end that resolves (mirroring
+    /// [`Self::record_allocation`]). An end that doesn't resolve has no
+    /// target name to record an edge against, so it's reported as an
+    /// [`UNRESOLVED_SUCCESSION_ENDPOINT`](crate::diagnostics::UNRESOLVED_SUCCESSION_ENDPOINT)
+    /// diagnostic instead.
+    pub fn record_succession(
+        &mut self,
+        succession: QualifiedName,
+        scope: &QualifiedName,
+        source: &[&str],
+        target: &[&str],
+    ) -> Vec<crate::diagnostics::Diagnostic> {
+        use crate::diagnostics::{Diagnostic, Severity, UNRESOLVED_SUCCESSION_ENDPOINT};
+
+        let location = self.symbol_by_qualified_name(&succession).map(|s| (s.file.clone(), s.range()));
+        let mut diagnostics = Vec::new();
+
+        for (label, chain) in [("first", source), ("then", target)] {
+            match self.resolve_feature_chain(scope, chain) {
+                Some(resolved) => {
+                    let resolved_name = resolved.qualified_name.clone();
+                    self.relationships.add_edge(RelationshipKind::SuccessionEnd, succession.clone(), resolved_name);
+                }
+                None => {
+                    if let Some((file, span)) = location.clone() {
+                        diagnostics.push(Diagnostic::new(
+                            Severity::Error,
+                            UNRESOLVED_SUCCESSION_ENDPOINT,
+                            format!("succession {label} endpoint '{}' cannot be resolved within the enclosing behavior", chain.join(".")),
+                            file,
+                            span,
+                        ));
+                    }
+                }
+            }
+        }
+        diagnostics
+    }
+
+    /// Resolves a `calc def`'s trailing result expression — e.g. the bare
+    /// `c` in `calc def Increment { return : Counter; in c : Counter; c }`
+    /// — as a feature chain rooted at `scope` (the `calc def` itself),
+    /// recording a [`ResultExpression`](RelationshipKind::ResultExpression)
+    /// edge from `result_expression` to whatever it resolves to. Mirrors
+    /// [`Self::record_succession`]'s single-end case: an expression that
+    /// doesn't resolve has no target name for a dangling graph edge, so
+    /// it's reported as an
+    /// [`UNRESOLVED_RESULT_EXPRESSION`](crate::diagnostics::UNRESOLVED_RESULT_EXPRESSION)
+    /// diagnostic instead.
+    pub fn record_result_expression(
+        &mut self,
+        result_expression: QualifiedName,
+        scope: &QualifiedName,
+        expression: &[&str],
+    ) -> Option<crate::diagnostics::Diagnostic> {
+        use crate::diagnostics::{Diagnostic, Severity, UNRESOLVED_RESULT_EXPRESSION};
+
+        match self.resolve_feature_chain(scope, expression) {
+            Some(resolved) => {
+                let resolved_name = resolved.qualified_name.clone();
+                self.relationships.add_edge(RelationshipKind::ResultExpression, result_expression, resolved_name);
+                None
+            }
+            None => {
+                let (file, span) = self.symbol_by_qualified_name(&result_expression).map(|s| (s.file.clone(), s.range()))?;
+                Some(Diagnostic::new(
+                    Severity::Error,
+                    UNRESOLVED_RESULT_EXPRESSION,
+                    format!("result expression '{}' cannot be resolved within the enclosing calc", expression.join(".")),
+                    file,
+                    span,
+                ))
+            }
+        }
+    }
+
+    /// Resolves each identifier reference inside an `assert`/`assume`/
+    /// `require constraint`'s expression body (e.g. both
+    /// `innerSpaceDimension` and `value` in `assert constraint {
+    /// innerSpaceDimension == value }`) against `scope`, the enclosing
+    /// definition. Each reference that resolves gets a
+    /// [`ConstraintReference`](RelationshipKind::ConstraintReference) edge
+    /// from `constraint` to it, so navigation sees it; each that doesn't
+    /// reports an [`UNRESOLVED_REFERENCE`](crate::diagnostics::UNRESOLVED_REFERENCE)
+    /// diagnostic instead of a dangling edge.
+    pub fn record_constraint_references(
+        &mut self,
+        constraint: QualifiedName,
+        scope: &QualifiedName,
+        references: &[&[&str]],
+    ) -> Vec<crate::diagnostics::Diagnostic> {
+        use crate::diagnostics::{Diagnostic, Severity, UNRESOLVED_REFERENCE};
+
+        let location = self.symbol_by_qualified_name(&constraint).map(|s| (s.file.clone(), s.range()));
+        let mut diagnostics = Vec::new();
+
+        for chain in references {
+            match self.resolve_feature_chain(scope, chain) {
+                Some(resolved) => {
+                    let resolved_name = resolved.qualified_name.clone();
+                    self.relationships.add_edge(RelationshipKind::ConstraintReference, constraint.clone(), resolved_name);
+                }
+                None => {
+                    if let Some((file, span)) = location.clone() {
+                        diagnostics.push(Diagnostic::new(
+                            Severity::Error,
+                            UNRESOLVED_REFERENCE,
+                            format!("'{}' cannot be resolved within the enclosing constraint", chain.join(".")),
+                            file,
+                            span,
+                        ));
+                    }
+                }
+            }
+        }
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::semantic::symbol::SymbolKind;
+    use crate::span::{Position, Span};
+    use std::path::PathBuf;
+
+    #[test]
+    fn resolves_perform_feature_chain_across_nested_parts() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let mut ws = Workspace::default();
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine::start"), SymbolKind::ActionUsage, file, span));
+
+        let resolved = ws
+            .resolve_feature_chain(&QualifiedName::new("Vehicle"), &["engine", "start"])
+            .expect("perform engine.start should resolve");
+
+        assert_eq!(resolved.qualified_name, QualifiedName::new("Vehicle::engine::start"));
+    }
+
+    #[test]
+    fn resolves_a_binding_connector_chain_through_the_usage_s_type() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Engine"), SymbolKind::PartDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Engine::y"), SymbolKind::AttributeUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::b"), SymbolKind::PartUsage, file, span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::b"), QualifiedName::new("Engine"));
+
+        let resolved = ws
+            .resolve_feature_chain(&QualifiedName::new("Vehicle"), &["b", "y"])
+            .expect("bind x = b.y should resolve y on b's type");
+
+        assert_eq!(resolved.qualified_name, QualifiedName::new("Engine::y"));
+    }
+
+    #[test]
+    fn find_references_on_the_bind_target_includes_the_binding_connector() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let y_span = Span::new(Position::new(1, 4), Position::new(1, 5));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Engine"), SymbolKind::PartDefinition, file.clone(), Span::new(Position::new(0, 0), Position::new(0, 6))));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Engine::y"), SymbolKind::AttributeUsage, file.clone(), y_span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::b"), SymbolKind::PartUsage, file.clone(), Span::new(Position::new(2, 0), Position::new(2, 1))));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::b"), QualifiedName::new("Engine"));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::x"), SymbolKind::AttributeUsage, file.clone(), Span::new(Position::new(3, 0), Position::new(3, 1))));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::binding1"), SymbolKind::Connection, file.clone(), Span::new(Position::new(4, 0), Position::new(4, 1))));
+
+        let target = ws.resolve_feature_chain(&QualifiedName::new("Vehicle"), &["b", "y"]).unwrap().qualified_name.clone();
+        assert_eq!(target, QualifiedName::new("Engine::y"));
+        ws.relationships.add_edge(RelationshipKind::Bind, QualifiedName::new("Vehicle::binding1"), QualifiedName::new("Vehicle::x"));
+        ws.relationships.add_edge(RelationshipKind::Bind, QualifiedName::new("Vehicle::binding1"), target);
+
+        let references = ws.find_references(&file, y_span.start);
+        let names: Vec<_> = references.iter().map(|s| s.qualified_name.to_string()).collect();
+
+        assert!(names.contains(&"Engine::y".to_string()));
+        assert!(names.contains(&"Vehicle::binding1".to_string()));
+    }
+
+    #[test]
+    fn an_allocation_s_ends_resolve_and_appear_as_edges_in_the_relationship_graph() {
+        let file = PathBuf::from("Assembly.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Assembly::l"), SymbolKind::PartUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Assembly::l::component"), SymbolKind::PartUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Assembly::p"), SymbolKind::PartUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Assembly::p::element"), SymbolKind::PartUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Assembly::allocation1"), SymbolKind::Connection, file, span));
+
+        ws.record_allocation(
+            QualifiedName::new("Assembly::allocation1"),
+            &QualifiedName::new("Assembly"),
+            &[&["l", "component"], &["p", "element"]],
+        );
+
+        let mut referencing = ws.relationships.referencing(&QualifiedName::new("Assembly::l::component"));
+        referencing.extend(ws.relationships.referencing(&QualifiedName::new("Assembly::p::element")));
+
+        assert_eq!(
+            referencing,
+            vec![
+                (RelationshipKind::AllocationEnd, QualifiedName::new("Assembly::allocation1")),
+                (RelationshipKind::AllocationEnd, QualifiedName::new("Assembly::allocation1")),
+            ]
+        );
+    }
+
+    // The `logical ::>`/`physical ::>` role labels in `allocate ( logical ::>
+    // l, physical ::> p )` are just names for the ends, not themselves
+    // navigable — the caller passes the reference-subsetted target
+    // (`["l"]`, `["p"]`) through unchanged, so `record_allocation` already
+    // resolves and records each one exactly as it does for the two-ends
+    // form above. This test locks in that `l` and `p` resolve to their own
+    // declarations and are reachable via `referencing`, so goto-definition
+    // and find-references on a `::>` end already work without further
+    // changes.
+    #[test]
+    fn a_nary_allocation_s_reference_subsetted_ends_resolve_to_their_own_declarations() {
+        let file = PathBuf::from("Assembly.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Assembly::l"), SymbolKind::PartUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Assembly::p"), SymbolKind::PartUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Assembly::allocation1"), SymbolKind::Connection, file, span));
+
+        ws.record_allocation(QualifiedName::new("Assembly::allocation1"), &QualifiedName::new("Assembly"), &[&["l"], &["p"]]);
+
+        assert_eq!(ws.goto_definition_by_name(&QualifiedName::new("Assembly::l")).unwrap().qualified_name, QualifiedName::new("Assembly::l"));
+        assert_eq!(ws.goto_definition_by_name(&QualifiedName::new("Assembly::p")).unwrap().qualified_name, QualifiedName::new("Assembly::p"));
+
+        assert_eq!(
+            ws.relationships.referencing(&QualifiedName::new("Assembly::l")),
+            vec![(RelationshipKind::AllocationEnd, QualifiedName::new("Assembly::allocation1"))]
+        );
+        assert_eq!(
+            ws.relationships.referencing(&QualifiedName::new("Assembly::p")),
+            vec![(RelationshipKind::AllocationEnd, QualifiedName::new("Assembly::allocation1"))]
+        );
+    }
+
+    #[test]
+    fn a_valid_succession_navigates_both_endpoints_and_reports_no_diagnostics() {
+        let file = PathBuf::from("Behavior.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Behavior::s1"), SymbolKind::ActionUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Behavior::s2"), SymbolKind::ActionUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Behavior::succession1"), SymbolKind::Connection, file, span));
+
+        let diagnostics = ws.record_succession(QualifiedName::new("Behavior::succession1"), &QualifiedName::new("Behavior"), &["s1"], &["s2"]);
+
+        assert!(diagnostics.is_empty());
+        assert_eq!(
+            ws.relationships.edges(RelationshipKind::SuccessionEnd, &QualifiedName::new("Behavior::succession1")),
+            &[QualifiedName::new("Behavior::s1"), QualifiedName::new("Behavior::s2")]
+        );
+    }
+
+    #[test]
+    fn a_succession_with_an_unresolved_endpoint_reports_a_diagnostic() {
+        let file = PathBuf::from("Behavior.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Behavior::s1"), SymbolKind::ActionUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Behavior::succession1"), SymbolKind::Connection, file, span));
+
+        let diagnostics = ws.record_succession(QualifiedName::new("Behavior::succession1"), &QualifiedName::new("Behavior"), &["s1"], &["s2"]);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, crate::diagnostics::UNRESOLVED_SUCCESSION_ENDPOINT);
+        assert!(diagnostics[0].message.contains("s2"));
+        assert_eq!(
+            ws.relationships.edges(RelationshipKind::SuccessionEnd, &QualifiedName::new("Behavior::succession1")),
+            &[QualifiedName::new("Behavior::s1")],
+            "the resolved endpoint should still be recorded even though the other failed"
+        );
+    }
+
+    #[test]
+    fn a_calc_s_result_expression_resolves_to_the_parameter_it_references_and_navigates_to_its_declaration() {
+        let file = PathBuf::from("Increment.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Counter"), SymbolKind::PartDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Increment::return"), SymbolKind::AttributeUsage, file.clone(), span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Increment::return"), QualifiedName::new("Counter"));
+        let c_span = Span::new(Position::new(1, 4), Position::new(1, 5));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Increment::c"), SymbolKind::AttributeUsage, file.clone(), c_span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Increment::resultExpr"), SymbolKind::AttributeUsage, file.clone(), span));
+
+        let diagnostic = ws.record_result_expression(QualifiedName::new("Increment::resultExpr"), &QualifiedName::new("Increment"), &["c"]);
+
+        assert!(diagnostic.is_none());
+        assert_eq!(
+            ws.relationships.edges(RelationshipKind::ResultExpression, &QualifiedName::new("Increment::resultExpr")),
+            &[QualifiedName::new("Increment::c")]
+        );
+
+        // "navigates to its declaration": find-references from the
+        // parameter's own declaration site picks up the result expression
+        // that points at it, the same way a bind target's references do.
+        let references = ws.find_references(&file, c_span.start);
+        let names: Vec<_> = references.iter().map(|s| s.qualified_name.to_string()).collect();
+        assert!(names.contains(&"Increment::resultExpr".to_string()));
+    }
+
+    #[test]
+    fn an_unresolved_result_expression_reports_a_diagnostic_instead_of_a_dangling_edge() {
+        let file = PathBuf::from("Increment.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Increment::resultExpr"), SymbolKind::AttributeUsage, file, span));
+
+        let diagnostic = ws
+            .record_result_expression(QualifiedName::new("Increment::resultExpr"), &QualifiedName::new("Increment"), &["missing"])
+            .expect("an unresolved result expression should report a diagnostic");
+
+        assert_eq!(diagnostic.code, crate::diagnostics::UNRESOLVED_RESULT_EXPRESSION);
+        assert!(diagnostic.message.contains("missing"));
+        assert!(ws.relationships.edges(RelationshipKind::ResultExpression, &QualifiedName::new("Increment::resultExpr")).is_empty());
+    }
+
+    #[test]
+    fn a_constraint_expression_resolves_both_features_it_references() {
+        let file = PathBuf::from("Box.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Box::innerSpaceDimension"), SymbolKind::AttributeUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Box::value"), SymbolKind::AttributeUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Box::constraint1"), SymbolKind::AttributeUsage, file, span));
+
+        let diagnostics = ws.record_constraint_references(
+            QualifiedName::new("Box::constraint1"),
+            &QualifiedName::new("Box"),
+            &[&["innerSpaceDimension"], &["value"]],
+        );
+
+        assert!(diagnostics.is_empty());
+        let mut referenced = ws.relationships.edges(RelationshipKind::ConstraintReference, &QualifiedName::new("Box::constraint1")).to_vec();
+        referenced.sort_by_key(|name| name.to_string());
+        assert_eq!(referenced, vec![QualifiedName::new("Box::innerSpaceDimension"), QualifiedName::new("Box::value")]);
+    }
+
+    #[test]
+    fn an_unresolved_constraint_reference_reports_a_diagnostic_alongside_the_resolved_one() {
+        let file = PathBuf::from("Box.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Box::value"), SymbolKind::AttributeUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Box::constraint1"), SymbolKind::AttributeUsage, file, span));
+
+        let diagnostics = ws.record_constraint_references(
+            QualifiedName::new("Box::constraint1"),
+            &QualifiedName::new("Box"),
+            &[&["missing"], &["value"]],
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, crate::diagnostics::UNRESOLVED_REFERENCE);
+        assert!(diagnostics[0].message.contains("missing"));
+        assert_eq!(
+            ws.relationships.edges(RelationshipKind::ConstraintReference, &QualifiedName::new("Box::constraint1")),
+            &[QualifiedName::new("Box::value")]
+        );
+    }
+}
@@ -0,0 +1,73 @@
+//! Feature value expressions (`= expr`, `:= expr`, `default expr`),
+//! recorded so hover can show what a feature was actually assigned.
+
+use std::collections::HashMap;
+
+use crate::semantic::qualified_name::QualifiedName;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub enum ValueKind {
+    /// `= expr` — an initial (overridable) value.
+    Initial,
+    /// `:= expr` — a binding expression, re-evaluated on access.
+    Binding,
+    /// `default expr` — used only when nothing else provides a value.
+    Default,
+}
+
+impl ValueKind {
+    fn operator(self) -> &'static str {
+        match self {
+            ValueKind::Initial => "=",
+            ValueKind::Binding => ":=",
+            ValueKind::Default => "default",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeatureValue {
+    pub kind: ValueKind,
+    pub expression_text: String,
+}
+
+impl FeatureValue {
+    pub fn new(kind: ValueKind, expression_text: impl Into<String>) -> Self {
+        Self { kind, expression_text: expression_text.into() }
+    }
+
+    /// Markdown fragment shown in hover, e.g. ``:= 9.81 * mass``.
+    pub fn render(&self) -> String {
+        format!("`{} {}`", self.kind.operator(), self.expression_text)
+    }
+}
+
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeatureValueTable {
+    values: HashMap<QualifiedName, FeatureValue>,
+}
+
+impl FeatureValueTable {
+    pub fn set(&mut self, name: QualifiedName, value: FeatureValue) {
+        self.values.insert(name, value);
+    }
+
+    pub fn get(&self, name: &QualifiedName) -> Option<&FeatureValue> {
+        self.values.get(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_each_value_kind_with_its_operator() {
+        assert_eq!(FeatureValue::new(ValueKind::Initial, "0").render(), "`= 0`");
+        assert_eq!(FeatureValue::new(ValueKind::Binding, "9.81 * mass").render(), "`:= 9.81 * mass`");
+        assert_eq!(FeatureValue::new(ValueKind::Default, "1").render(), "`default 1`");
+    }
+}
@@ -0,0 +1,62 @@
+//! Resolution of identifiers referenced inside `import Package::*[filter]`
+//! element-filter expressions (e.g. `import Stuff::*[@Deprecated]`).
+//!
+//! Filter expressions are parsed as ordinary expression ASTs, but until now
+//! nothing fed their identifier references through to the symbol table, so
+//! goto-definition and find-references silently failed inside filters.
+
+use crate::semantic::qualified_name::QualifiedName;
+use crate::semantic::symbol::Symbol;
+use crate::span::Span;
+use crate::workspace::Workspace;
+
+/// One `name` token referenced inside a `[...]` element-filter expression,
+/// with the span of that occurrence in the importing file.
+pub struct FilterReference {
+    pub name: QualifiedName,
+    pub span: Span,
+}
+
+impl Workspace {
+    /// Resolves each identifier in a filter expression against the symbol
+    /// table, in declaration order. Unresolved identifiers yield `None`
+    /// rather than failing the whole filter.
+    pub fn resolve_filter_references<'a>(
+        &'a self,
+        references: &[FilterReference],
+    ) -> Vec<Option<&'a Symbol>> {
+        references
+            .iter()
+            .map(|r| self.symbol_by_qualified_name(&r.name))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::semantic::symbol::SymbolKind;
+    use crate::span::Position;
+    use std::path::PathBuf;
+
+    #[test]
+    fn resolves_metadata_reference_inside_element_filter() {
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(
+            QualifiedName::new("Deprecated"),
+            SymbolKind::Package,
+            PathBuf::from("stdlib.kerml"),
+            Span::new(Position::new(0, 0), Position::new(0, 10)),
+        ));
+
+        let refs = vec![FilterReference {
+            name: QualifiedName::new("Deprecated"),
+            span: Span::new(Position::new(0, 20), Position::new(0, 30)),
+        }];
+
+        let resolved = ws.resolve_filter_references(&refs);
+        assert_eq!(resolved.len(), 1);
+        assert!(resolved[0].is_some());
+        assert_eq!(resolved[0].unwrap().qualified_name, QualifiedName::new("Deprecated"));
+    }
+}
@@ -0,0 +1,29 @@
+//! Symbol tables, qualified names, and cross-reference resolution.
+
+pub mod doc_comment;
+pub mod feature_chain;
+pub mod feature_value;
+pub mod filter_resolution;
+pub mod qualified_name;
+pub mod multiplicity;
+pub mod operators;
+pub mod relationship_graph;
+pub mod resolution_cache;
+pub mod resolver;
+pub mod stdlib;
+pub mod symbol;
+pub mod symbol_index;
+pub mod traceability;
+
+pub use doc_comment::DocCommentTable;
+pub use feature_value::{FeatureValue, FeatureValueTable, ValueKind};
+pub use filter_resolution::FilterReference;
+pub use qualified_name::QualifiedName;
+pub use relationship_graph::{RelationshipGraph, RelationshipKind};
+pub use multiplicity::{Multiplicity, MultiplicityTable};
+pub use operators::operator_hover;
+pub use resolution_cache::ResolutionCache;
+pub use resolver::{Resolver, TraceStep};
+pub use symbol::{Symbol, SymbolId, SymbolKind};
+pub use symbol_index::SymbolIndex;
+pub use traceability::TraceabilityRow;
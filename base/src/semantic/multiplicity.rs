@@ -0,0 +1,68 @@
+//! Feature multiplicity (`[0..1]`, `[1..*]`, ...) and port conjugation
+//! (`~Port`), tracked per symbol separately from [`Symbol`] itself.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::semantic::qualified_name::QualifiedName;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct Multiplicity {
+    pub lower: u32,
+    pub upper: Option<u32>,
+}
+
+impl Multiplicity {
+    pub fn exact(n: u32) -> Self {
+        Self { lower: n, upper: Some(n) }
+    }
+
+    pub fn new(lower: u32, upper: Option<u32>) -> Self {
+        Self { lower, upper }
+    }
+
+    /// Renders as SysML multiplicity notation, e.g. `[0..*]` or `[1..1]`.
+    pub fn render(&self) -> String {
+        match self.upper {
+            Some(upper) => format!("[{}..{}]", self.lower, upper),
+            None => format!("[{}..*]", self.lower),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultiplicityTable {
+    multiplicities: HashMap<QualifiedName, Multiplicity>,
+    conjugated: HashSet<QualifiedName>,
+}
+
+impl MultiplicityTable {
+    pub fn set_multiplicity(&mut self, name: QualifiedName, multiplicity: Multiplicity) {
+        self.multiplicities.insert(name, multiplicity);
+    }
+
+    pub fn multiplicity(&self, name: &QualifiedName) -> Option<Multiplicity> {
+        self.multiplicities.get(name).copied()
+    }
+
+    pub fn mark_conjugated(&mut self, name: QualifiedName) {
+        self.conjugated.insert(name);
+    }
+
+    pub fn is_conjugated(&self, name: &QualifiedName) -> bool {
+        self.conjugated.contains(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_bounded_and_unbounded_multiplicities() {
+        assert_eq!(Multiplicity::new(0, Some(1)).render(), "[0..1]");
+        assert_eq!(Multiplicity::new(0, None).render(), "[0..*]");
+        assert_eq!(Multiplicity::exact(1).render(), "[1..1]");
+    }
+}
@@ -0,0 +1,64 @@
+//! Hover text for KerML/SysML v2 expression operators.
+//!
+//! There's no real lexer/token stream in this tree yet (see
+//! [`crate::ast::scan`]'s doc comment for the same caveat on block
+//! structure), so this is a standalone lookup keyed by the operator's
+//! literal text rather than something wired into
+//! [`crate::workspace::Workspace::hover`] — a caller with a real token at
+//! a cursor position (once one exists) calls [`operator_hover`] directly
+//! with that token's text.
+
+/// `(token, meaning, precedence)`, ordered roughly from lowest to highest
+/// precedence, matching the KerML expression grammar's operator table.
+const OPERATORS: &[(&str, &str, &str)] = &[
+    ("implies", "logical implication", "low"),
+    ("xor", "exclusive or", "low"),
+    ("or", "logical or", "low"),
+    ("and", "logical and", "low"),
+    ("??", "null-coalescing — evaluates to the left operand unless it's null, otherwise the right", "low"),
+    ("==", "equality", "medium"),
+    ("!=", "inequality", "medium"),
+    ("hastype", "type test — true if the left operand is classified by the right operand's type", "high"),
+    ("istype", "strict type test — true if the left operand's type is exactly the right operand's type", "high"),
+    ("@@", "metadata access — evaluates to the metadata feature the left operand is annotated by", "high"),
+    ("as", "type cast", "high"),
+];
+
+/// Looks up `token` among the known operators, returning a short Markdown
+/// note (name, meaning, and precedence level) suitable for hover text.
+pub fn operator_hover(token: &str) -> Option<String> {
+    OPERATORS
+        .iter()
+        .find(|(op, _, _)| *op == token)
+        .map(|(op, meaning, precedence)| format!("`{op}` — {meaning}, {precedence} precedence"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hovering_implies_describes_it_as_low_precedence_implication() {
+        let hover = operator_hover("implies").unwrap();
+        assert!(hover.contains("implication"));
+        assert!(hover.contains("low precedence"));
+    }
+
+    #[test]
+    fn hovering_the_null_coalescing_operator_describes_its_fallback_behavior() {
+        let hover = operator_hover("??").unwrap();
+        assert!(hover.contains("null-coalescing"));
+    }
+
+    #[test]
+    fn hovering_hastype_describes_it_as_a_high_precedence_type_test() {
+        let hover = operator_hover("hastype").unwrap();
+        assert!(hover.contains("type test"));
+        assert!(hover.contains("high precedence"));
+    }
+
+    #[test]
+    fn an_unknown_token_has_no_operator_hover() {
+        assert!(operator_hover("+=").is_none());
+    }
+}
@@ -0,0 +1,43 @@
+//! `Package::Element`-style qualified names used to cross-reference symbols.
+
+/// A `::`-separated path identifying a symbol independent of
+/// which file it was declared in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct QualifiedName(String);
+
+impl QualifiedName {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self(path.into())
+    }
+
+    pub fn segments(&self) -> impl Iterator<Item = &str> {
+        self.0.split("::")
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The final segment, e.g. `Feature` in `Package::Class::Feature`.
+    pub fn simple_name(&self) -> &str {
+        self.segments().last().unwrap_or(self.0.as_str())
+    }
+
+    pub fn join(&self, segment: &str) -> QualifiedName {
+        QualifiedName(format!("{}::{}", self.0, segment))
+    }
+
+    /// The qualified name of the enclosing scope, e.g. `Package::Color` for
+    /// `Package::Color::red`. `None` for a top-level name.
+    pub fn parent(&self) -> Option<QualifiedName> {
+        let (parent, _) = self.0.rsplit_once("::")?;
+        Some(QualifiedName(parent.to_string()))
+    }
+}
+
+impl std::fmt::Display for QualifiedName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
@@ -0,0 +1,332 @@
+//! Specialization, typing, subsetting, redefinition and other cross-symbol
+//! relationships, kept out of [`Symbol`](super::symbol::Symbol) itself so
+//! they can be queried and cycle-checked independently.
+
+use std::collections::HashMap;
+
+use super::qualified_name::QualifiedName;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub enum RelationshipKind {
+    Specialization,
+    Typing,
+    Subsetting,
+    Redefinition,
+    /// A requirement's `subject` declaration.
+    Subject,
+    /// A requirement's `about` declaration.
+    About,
+    /// `satisfy <requirement> by <usage>`.
+    Satisfy,
+    /// `assert constraint <constraint-usage>`.
+    Assert,
+    /// `verify <requirement> by <case>`.
+    Verify,
+    /// A `connection`/`interface`'s end, in declaration order.
+    ConnectionEnd,
+    /// An `import Package::Member` statement, from the importing scope to
+    /// the imported member.
+    Import,
+    /// One end of a `bind x = b.y;` binding connector, from the connector
+    /// to each side (`x`, then the feature-chain's resolved target).
+    Bind,
+    /// One end of an `allocate l.component to assembly.element;` (or n-ary
+    /// `allocate ( logical ::> l, physical ::> p )`) allocation, from the
+    /// allocation usage to each resolved end.
+    AllocationEnd,
+    /// One end of a `first <source> then <target>;` succession, from the
+    /// succession usage to each resolved end.
+    SuccessionEnd,
+    /// From a `calc def`'s trailing result expression to the feature it
+    /// references, e.g. `c` in `calc def Increment { return : Counter; in
+    /// c : Counter; c }`.
+    ResultExpression,
+    /// From a `snapshot`/`timeslice` portion usage to the occurrence it's a
+    /// portion of.
+    PortionOf,
+    /// From a `view`'s `expose <target>;` statement to the exposed
+    /// namespace or member.
+    Expose,
+    /// From an `assert`/`assume`/`require constraint`'s expression body to
+    /// each feature it references, e.g. both `innerSpaceDimension` and
+    /// `value` in `assert constraint { innerSpaceDimension == value }`.
+    ConstraintReference,
+    /// A requirement's `frame <concern>;` framed-concern declaration.
+    Frame,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelationshipGraph {
+    edges: HashMap<(RelationshipKind, QualifiedName), Vec<QualifiedName>>,
+}
+
+impl RelationshipGraph {
+    /// Records `from` related to `to` via `kind` (e.g. `from specializes to`).
+    pub fn add_edge(&mut self, kind: RelationshipKind, from: QualifiedName, to: QualifiedName) {
+        self.edges.entry((kind, from)).or_default().push(to);
+    }
+
+    pub fn edges(&self, kind: RelationshipKind, from: &QualifiedName) -> &[QualifiedName] {
+        self.edges.get(&(kind, from.clone())).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Approximate heap usage of the edge table: capacity-based container
+    /// overhead plus each key/target `QualifiedName`'s string bytes. Used
+    /// by [`crate::workspace::Workspace::estimated_memory_bytes`] — not
+    /// exact, since allocator bookkeeping and string capacity (vs. length)
+    /// aren't visible from here.
+    pub fn estimated_memory_bytes(&self) -> usize {
+        let mut total = self.edges.capacity() * std::mem::size_of::<((RelationshipKind, QualifiedName), Vec<QualifiedName>)>();
+        for ((_, from), targets) in &self.edges {
+            total += from.as_str().len();
+            total += targets.capacity() * std::mem::size_of::<QualifiedName>();
+            total += targets.iter().map(|t| t.as_str().len()).sum::<usize>();
+        }
+        total
+    }
+
+    /// Every `(kind, from)` pair with an edge pointing at `target`, e.g. the
+    /// usages typed by a definition or the imports naming it. Used by
+    /// find-references, which cares about who points at a symbol rather
+    /// than what a symbol points at.
+    pub fn referencing(&self, target: &QualifiedName) -> Vec<(RelationshipKind, QualifiedName)> {
+        self.edges
+            .iter()
+            .filter(|(_, targets)| targets.contains(target))
+            .map(|((kind, from), _)| (*kind, from.clone()))
+            .collect()
+    }
+
+    /// The full specialization chain for `name`, starting with `name`
+    /// itself and following `specializes` edges until reaching a root (or
+    /// detecting a cycle, at which point traversal stops rather than
+    /// looping forever).
+    pub fn specialization_chain(&self, name: &QualifiedName) -> Vec<QualifiedName> {
+        let mut chain = vec![name.clone()];
+        let mut current = name.clone();
+        let mut seen = std::collections::HashSet::new();
+        seen.insert(current.clone());
+
+        while let Some(parent) = self.edges(RelationshipKind::Specialization, &current).first() {
+            if !seen.insert(parent.clone()) {
+                break;
+            }
+            chain.push(parent.clone());
+            current = parent.clone();
+        }
+        chain
+    }
+
+    /// Whether following `name`'s specialization edges ever revisits a name
+    /// already seen — i.e. `name` participates in a specialization cycle.
+    /// Shares [`Self::specialization_chain`]'s traversal, but reports the
+    /// cycle instead of silently stopping at it.
+    pub fn has_specialization_cycle(&self, name: &QualifiedName) -> bool {
+        let mut current = name.clone();
+        let mut seen = std::collections::HashSet::new();
+        seen.insert(current.clone());
+
+        while let Some(parent) = self.edges(RelationshipKind::Specialization, &current).first() {
+            if !seen.insert(parent.clone()) {
+                return true;
+            }
+            current = parent.clone();
+        }
+        false
+    }
+
+    /// `name`'s direct specialization parents, or (`transitive: true`)
+    /// every ancestor reached by repeatedly following `Specialization`
+    /// edges. Unlike [`Self::specialization_chain`] (which follows only the
+    /// first parent, for the common single-inheritance display case), this
+    /// follows every parent, so a diamond's shared ancestor is visited once
+    /// rather than reported twice. BFS order: nearest ancestors first.
+    pub fn supertypes_of(&self, name: &QualifiedName, transitive: bool) -> Vec<QualifiedName> {
+        self.specialization_bfs(name, transitive, |graph, current| graph.edges(RelationshipKind::Specialization, current).to_vec())
+    }
+
+    /// The reverse of [`Self::supertypes_of`]: names that directly
+    /// specialize `name`, or (`transitive: true`) every descendant reached
+    /// transitively, BFS nearest first, each visited once even across a
+    /// diamond.
+    pub fn specializations_of(&self, name: &QualifiedName, transitive: bool) -> Vec<QualifiedName> {
+        self.specialization_bfs(name, transitive, |graph, current| {
+            // `referencing` scans the edge map, whose iteration order isn't
+            // meaningful (unlike `edges`, which preserves declaration
+            // order), so each level is sorted for a deterministic result.
+            let mut children: Vec<QualifiedName> = graph
+                .referencing(current)
+                .into_iter()
+                .filter(|(kind, _)| *kind == RelationshipKind::Specialization)
+                .map(|(_, from)| from)
+                .collect();
+            children.sort();
+            children
+        })
+    }
+
+    /// Shared BFS walk for [`Self::supertypes_of`] and
+    /// [`Self::specializations_of`]: `neighbors` picks the direction
+    /// (parents or children). `transitive: false` stops after the first
+    /// level; `transitive: true` keeps expanding until the frontier is
+    /// exhausted. A name already seen (a diamond's shared ancestor, or a
+    /// cycle) is never added to the frontier again.
+    fn specialization_bfs(&self, name: &QualifiedName, transitive: bool, neighbors: impl Fn(&Self, &QualifiedName) -> Vec<QualifiedName>) -> Vec<QualifiedName> {
+        let mut seen = std::collections::HashSet::new();
+        seen.insert(name.clone());
+        let mut frontier = vec![name.clone()];
+        let mut result = Vec::new();
+
+        loop {
+            let mut next_frontier = Vec::new();
+            for current in &frontier {
+                for neighbor in neighbors(self, current) {
+                    if seen.insert(neighbor.clone()) {
+                        result.push(neighbor.clone());
+                        next_frontier.push(neighbor);
+                    }
+                }
+            }
+            if !transitive || next_frontier.is_empty() {
+                break;
+            }
+            frontier = next_frontier;
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn satisfy_assert_verify_edges_are_independently_queryable() {
+        let mut graph = RelationshipGraph::default();
+        graph.add_edge(RelationshipKind::Satisfy, QualifiedName::new("R1"), QualifiedName::new("Vehicle::engine"));
+        graph.add_edge(RelationshipKind::Verify, QualifiedName::new("R1"), QualifiedName::new("EngineTestCase"));
+        graph.add_edge(RelationshipKind::Assert, QualifiedName::new("C1"), QualifiedName::new("Vehicle::mass"));
+
+        assert_eq!(graph.edges(RelationshipKind::Satisfy, &QualifiedName::new("R1")), &[QualifiedName::new("Vehicle::engine")]);
+        assert_eq!(graph.edges(RelationshipKind::Verify, &QualifiedName::new("R1")), &[QualifiedName::new("EngineTestCase")]);
+        assert_eq!(graph.edges(RelationshipKind::Assert, &QualifiedName::new("C1")), &[QualifiedName::new("Vehicle::mass")]);
+        assert!(graph.edges(RelationshipKind::Satisfy, &QualifiedName::new("C1")).is_empty());
+    }
+
+    #[test]
+    fn specialization_chain_follows_edges_to_the_root() {
+        let mut graph = RelationshipGraph::default();
+        graph.add_edge(RelationshipKind::Specialization, QualifiedName::new("Car"), QualifiedName::new("Vehicle"));
+        graph.add_edge(RelationshipKind::Specialization, QualifiedName::new("Vehicle"), QualifiedName::new("Thing"));
+
+        let chain = graph.specialization_chain(&QualifiedName::new("Car"));
+
+        assert_eq!(
+            chain,
+            vec![QualifiedName::new("Car"), QualifiedName::new("Vehicle"), QualifiedName::new("Thing")]
+        );
+    }
+
+    #[test]
+    fn referencing_finds_every_edge_kind_pointing_at_a_target() {
+        let mut graph = RelationshipGraph::default();
+        graph.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::engine"), QualifiedName::new("Engine"));
+        graph.add_edge(RelationshipKind::Import, QualifiedName::new("OtherPkg"), QualifiedName::new("Engine"));
+        graph.add_edge(RelationshipKind::About, QualifiedName::new("Note1"), QualifiedName::new("Engine"));
+        graph.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::spare"), QualifiedName::new("Wheel"));
+
+        let mut referencing = graph.referencing(&QualifiedName::new("Engine"));
+        referencing.sort_by_key(|(_, from)| from.to_string());
+
+        assert_eq!(
+            referencing,
+            vec![
+                (RelationshipKind::About, QualifiedName::new("Note1")),
+                (RelationshipKind::Import, QualifiedName::new("OtherPkg")),
+                (RelationshipKind::Typing, QualifiedName::new("Vehicle::engine")),
+            ]
+        );
+    }
+
+    #[test]
+    fn specialization_chain_stops_on_a_cycle() {
+        let mut graph = RelationshipGraph::default();
+        graph.add_edge(RelationshipKind::Specialization, QualifiedName::new("A"), QualifiedName::new("B"));
+        graph.add_edge(RelationshipKind::Specialization, QualifiedName::new("B"), QualifiedName::new("A"));
+
+        let chain = graph.specialization_chain(&QualifiedName::new("A"));
+
+        assert_eq!(chain, vec![QualifiedName::new("A"), QualifiedName::new("B")]);
+    }
+
+    #[test]
+    fn has_specialization_cycle_is_true_for_a_cycle_and_false_for_a_clean_chain() {
+        let mut graph = RelationshipGraph::default();
+        graph.add_edge(RelationshipKind::Specialization, QualifiedName::new("A"), QualifiedName::new("B"));
+        graph.add_edge(RelationshipKind::Specialization, QualifiedName::new("B"), QualifiedName::new("A"));
+        graph.add_edge(RelationshipKind::Specialization, QualifiedName::new("Car"), QualifiedName::new("Vehicle"));
+
+        assert!(graph.has_specialization_cycle(&QualifiedName::new("A")));
+        assert!(!graph.has_specialization_cycle(&QualifiedName::new("Car")));
+    }
+
+    /// `Sedan :> Car :> Vehicle`, plus a diamond where both `Car` and
+    /// `Truck` specialize `Vehicle`, and `HybridCar` specializes both `Car`
+    /// and `Electric` (itself specializing `Vehicle`), so `Vehicle` is
+    /// reachable from `HybridCar` by two paths.
+    fn diamond_hierarchy() -> RelationshipGraph {
+        let mut graph = RelationshipGraph::default();
+        graph.add_edge(RelationshipKind::Specialization, QualifiedName::new("Sedan"), QualifiedName::new("Car"));
+        graph.add_edge(RelationshipKind::Specialization, QualifiedName::new("Car"), QualifiedName::new("Vehicle"));
+        graph.add_edge(RelationshipKind::Specialization, QualifiedName::new("Truck"), QualifiedName::new("Vehicle"));
+        graph.add_edge(RelationshipKind::Specialization, QualifiedName::new("HybridCar"), QualifiedName::new("Car"));
+        graph.add_edge(RelationshipKind::Specialization, QualifiedName::new("HybridCar"), QualifiedName::new("Electric"));
+        graph.add_edge(RelationshipKind::Specialization, QualifiedName::new("Electric"), QualifiedName::new("Vehicle"));
+        graph
+    }
+
+    #[test]
+    fn supertypes_of_direct_returns_only_the_immediate_parents() {
+        let graph = diamond_hierarchy();
+
+        assert_eq!(graph.supertypes_of(&QualifiedName::new("Sedan"), false), vec![QualifiedName::new("Car")]);
+
+        let parents = graph.supertypes_of(&QualifiedName::new("HybridCar"), false);
+        assert_eq!(parents, vec![QualifiedName::new("Car"), QualifiedName::new("Electric")]);
+    }
+
+    #[test]
+    fn supertypes_of_transitive_reaches_a_shared_ancestor_through_a_diamond_exactly_once() {
+        let graph = diamond_hierarchy();
+
+        let ancestors = graph.supertypes_of(&QualifiedName::new("HybridCar"), true);
+
+        assert_eq!(
+            ancestors,
+            vec![QualifiedName::new("Car"), QualifiedName::new("Electric"), QualifiedName::new("Vehicle")]
+        );
+    }
+
+    #[test]
+    fn specializations_of_direct_and_transitive_find_descendants_nearest_first_without_duplicates() {
+        let graph = diamond_hierarchy();
+
+        let direct = graph.specializations_of(&QualifiedName::new("Vehicle"), false);
+        assert_eq!(direct, vec![QualifiedName::new("Car"), QualifiedName::new("Electric"), QualifiedName::new("Truck")]);
+
+        let transitive = graph.specializations_of(&QualifiedName::new("Vehicle"), true);
+        assert_eq!(
+            transitive,
+            vec![
+                QualifiedName::new("Car"),
+                QualifiedName::new("Electric"),
+                QualifiedName::new("Truck"),
+                QualifiedName::new("HybridCar"),
+                QualifiedName::new("Sedan"),
+            ]
+        );
+    }
+}
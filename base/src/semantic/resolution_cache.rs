@@ -0,0 +1,109 @@
+//! Caches repeated [`QualifiedName`] lookups against a [`Workspace`], with
+//! invalidation scoped to whichever files actually changed on reparse
+//! rather than a blanket clear.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::semantic::qualified_name::QualifiedName;
+use crate::semantic::symbol::Symbol;
+use crate::workspace::Workspace;
+
+#[derive(Debug, Default)]
+pub struct ResolutionCache {
+    hits: RefCell<HashMap<QualifiedName, Option<Symbol>>>,
+    /// Which cached names came from which file, so a reparse of that file
+    /// only invalidates the entries it could have affected.
+    names_by_file: RefCell<HashMap<PathBuf, Vec<QualifiedName>>>,
+}
+
+impl ResolutionCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolves `name` against `workspace`, caching the result (including
+    /// `None`) so a repeated lookup skips the symbol table entirely.
+    pub fn resolve(&self, workspace: &Workspace, name: &QualifiedName) -> Option<Symbol> {
+        if let Some(cached) = self.hits.borrow().get(name) {
+            return cached.clone();
+        }
+
+        let result = workspace.symbol_by_qualified_name(name).cloned();
+        if let Some(symbol) = &result {
+            self.names_by_file.borrow_mut().entry(symbol.file.clone()).or_default().push(name.clone());
+        }
+        self.hits.borrow_mut().insert(name.clone(), result.clone());
+        result
+    }
+
+    /// Drops every cached lookup that resolved to a symbol declared in
+    /// `file`, e.g. after that file is reparsed. Also drops every cached
+    /// miss (a `None` result): a miss isn't attributed to any file in
+    /// `names_by_file` (there's nothing to attribute it to — the lookup
+    /// found no declaration anywhere), so without this it would never be
+    /// evicted and a name that later gets declared in `file` would keep
+    /// replaying its earlier "unresolved" answer forever.
+    pub fn invalidate_file(&self, file: &Path) {
+        if let Some(names) = self.names_by_file.borrow_mut().remove(file) {
+            let mut hits = self.hits.borrow_mut();
+            for name in names {
+                hits.remove(&name);
+            }
+        }
+        self.hits.borrow_mut().retain(|_, result| result.is_some());
+    }
+
+    pub fn len(&self) -> usize {
+        self.hits.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::semantic::symbol::SymbolKind;
+    use crate::span::{Position, Span};
+
+    #[test]
+    fn invalidating_a_file_drops_only_names_declared_there() {
+        let mut ws = Workspace::default();
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::PartDefinition, PathBuf::from("a.sysml"), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Engine"), SymbolKind::PartDefinition, PathBuf::from("b.sysml"), span));
+
+        let cache = ResolutionCache::new();
+        cache.resolve(&ws, &QualifiedName::new("Vehicle"));
+        cache.resolve(&ws, &QualifiedName::new("Engine"));
+        assert_eq!(cache.len(), 2);
+
+        cache.invalidate_file(Path::new("a.sysml"));
+
+        assert_eq!(cache.len(), 1);
+        assert!(cache.hits.borrow().contains_key(&QualifiedName::new("Engine")));
+    }
+
+    #[test]
+    fn a_cached_miss_is_re_checked_after_any_invalidation_instead_of_replaying_forever() {
+        let mut ws = Workspace::default();
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::PartDefinition, PathBuf::from("a.sysml"), span));
+
+        let cache = ResolutionCache::new();
+        assert!(cache.resolve(&ws, &QualifiedName::new("Engine")).is_none(), "Engine isn't declared anywhere yet");
+        assert!(cache.hits.borrow().contains_key(&QualifiedName::new("Engine")), "the miss itself is cached");
+
+        // `Engine` gets declared in a newly (re)parsed file — nothing in
+        // `names_by_file` names it (a miss has no file to attribute to),
+        // so the unrelated invalidation of `a.sysml` must still drop it.
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Engine"), SymbolKind::PartDefinition, PathBuf::from("b.sysml"), span));
+        cache.invalidate_file(Path::new("a.sysml"));
+
+        assert_eq!(cache.resolve(&ws, &QualifiedName::new("Engine")).unwrap().qualified_name, QualifiedName::new("Engine"));
+    }
+}
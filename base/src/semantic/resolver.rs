@@ -0,0 +1,190 @@
+//! Name resolution against a [`Workspace`](crate::workspace::Workspace),
+//! with an optional trace for debugging why a name did or didn't resolve.
+
+use crate::semantic::qualified_name::QualifiedName;
+use crate::semantic::symbol::Symbol;
+use crate::workspace::Workspace;
+
+/// One step of a [`Resolver::resolve_with_trace`] attempt: the candidate
+/// name tried, and whether it matched a known symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceStep {
+    pub candidate: QualifiedName,
+    pub matched: bool,
+}
+
+pub struct Resolver<'a> {
+    workspace: &'a Workspace,
+}
+
+impl<'a> Resolver<'a> {
+    pub fn new(workspace: &'a Workspace) -> Self {
+        Self { workspace }
+    }
+
+    pub fn resolve(&self, name: &QualifiedName) -> Option<&'a Symbol> {
+        self.workspace.symbol_by_qualified_name(name)
+    }
+
+    /// Resolves `name` exactly like [`Resolver::resolve`], but also walking
+    /// up each enclosing scope from `scopes` (innermost first) until a
+    /// match is found, recording every candidate tried. Useful for
+    /// diagnosing "why didn't this resolve" reports.
+    pub fn resolve_with_trace(&self, name: &QualifiedName, scopes: &[QualifiedName]) -> (Option<&'a Symbol>, Vec<TraceStep>) {
+        let mut trace = Vec::new();
+
+        if let Some(symbol) = self.workspace.symbol_by_qualified_name(name) {
+            trace.push(TraceStep { candidate: name.clone(), matched: true });
+            return (Some(symbol), trace);
+        }
+        trace.push(TraceStep { candidate: name.clone(), matched: false });
+
+        for scope in scopes {
+            let candidate = scope.join(name.as_str());
+            let matched = self.workspace.symbol_by_qualified_name(&candidate);
+            trace.push(TraceStep { candidate: candidate.clone(), matched: matched.is_some() });
+            if let Some(symbol) = matched {
+                return (Some(symbol), trace);
+            }
+        }
+
+        (None, trace)
+    }
+
+    /// Resolves a default-value reference like `Case::result`, where a
+    /// leading `Case` segment is a self-relative qualifier for the nearest
+    /// enclosing analysis/verification case rather than a literal type
+    /// name — the same idea as `self`/`this`, but there's no dedicated
+    /// `AnalysisCase`/`VerificationCase`/`Objective` symbol kind in this
+    /// tree yet, so callers pass the enclosing case's qualified name
+    /// directly rather than this walking up the symbol table to find it.
+    pub fn resolve_case_relative(&self, name: &QualifiedName, enclosing_case: &QualifiedName) -> Option<&'a Symbol> {
+        if let Some(rest) = name.as_str().strip_prefix("Case::") {
+            if let Some(symbol) = self.workspace.symbol_by_qualified_name(&enclosing_case.join(rest)) {
+                return Some(symbol);
+            }
+        }
+        self.resolve(name)
+    }
+
+    /// Resolves a bare simple-name reference the way an expression inside a
+    /// definition body does implicitly — e.g. a `constraint` referencing a
+    /// sibling `attribute` by name, with no `self.`/`this.` qualifier
+    /// spelled out. Tries each scope's feature namespace, including
+    /// features inherited through its specialization chain
+    /// ([`Workspace::members_including_inherited`](crate::workspace::Workspace::members_including_inherited)
+    /// via [`Workspace::resolve_member`](crate::workspace::Workspace::resolve_member)),
+    /// innermost scope first, before falling back to
+    /// [`Resolver::resolve_with_trace`]'s plain scope-qualifying walk.
+    pub fn resolve_implicit_member(&self, name: &QualifiedName, scopes: &[QualifiedName]) -> Option<&'a Symbol> {
+        if name.segments().count() == 1 {
+            for scope in scopes {
+                if let Some(symbol) = self.workspace.resolve_member(scope, name.as_str()) {
+                    return Some(symbol);
+                }
+            }
+        }
+        self.resolve_with_trace(name, scopes).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::semantic::symbol::SymbolKind;
+    use crate::span::{Position, Span};
+    use std::path::PathBuf;
+
+    #[test]
+    fn trace_records_every_scope_tried_before_matching() {
+        let mut ws = Workspace::default();
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, PathBuf::from("a.sysml"), span));
+
+        let resolver = Resolver::new(&ws);
+        let (resolved, trace) = resolver.resolve_with_trace(&QualifiedName::new("engine"), &[QualifiedName::new("Vehicle")]);
+
+        assert!(resolved.is_some());
+        assert_eq!(trace.len(), 2);
+        assert!(!trace[0].matched);
+        assert!(trace[1].matched);
+        assert_eq!(trace[1].candidate, QualifiedName::new("Vehicle::engine"));
+    }
+
+    #[test]
+    fn a_for_loop_variable_resolves_inside_the_loop_but_not_after_it() {
+        let mut ws = Workspace::default();
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        // `action Run { for x in items { perform x.check; } }` — `x` is
+        // declared as a member of the loop itself, not of `Run`.
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Run::forLoop1::x"), SymbolKind::LoopVariable, PathBuf::from("a.sysml"), span));
+
+        let resolver = Resolver::new(&ws);
+
+        let (inside, _) = resolver.resolve_with_trace(&QualifiedName::new("x"), &[QualifiedName::new("Run::forLoop1")]);
+        assert!(inside.is_some(), "x should resolve from inside the loop body");
+
+        let (after, _) = resolver.resolve_with_trace(&QualifiedName::new("x"), &[QualifiedName::new("Run")]);
+        assert!(after.is_none(), "x must not leak into Run's own scope once the loop has ended");
+    }
+
+    #[test]
+    fn case_relative_default_value_resolves_to_the_enclosing_cases_result_feature() {
+        let mut ws = Workspace::default();
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        // `verification case Check { return result : Boolean; objective obj { subject subj default Case::result; } }`
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Check::result"), SymbolKind::AttributeUsage, PathBuf::from("a.sysml"), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Check::obj"), SymbolKind::AttributeUsage, PathBuf::from("a.sysml"), span));
+
+        let resolver = Resolver::new(&ws);
+        let resolved = resolver.resolve_case_relative(&QualifiedName::new("Case::result"), &QualifiedName::new("Check"));
+
+        assert_eq!(resolved.map(|s| &s.qualified_name), Some(&QualifiedName::new("Check::result")));
+    }
+
+    #[test]
+    fn a_default_value_without_the_case_qualifier_resolves_normally() {
+        let mut ws = Workspace::default();
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Check::result"), SymbolKind::AttributeUsage, PathBuf::from("a.sysml"), span));
+
+        let resolver = Resolver::new(&ws);
+        let resolved = resolver.resolve_case_relative(&QualifiedName::new("Check::result"), &QualifiedName::new("Check"));
+
+        assert_eq!(resolved.map(|s| &s.qualified_name), Some(&QualifiedName::new("Check::result")));
+    }
+
+    #[test]
+    fn a_constraint_expression_resolves_a_sibling_attribute_by_bare_name() {
+        let mut ws = Workspace::default();
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        // `part def Vehicle { attribute mass : Real; constraint { mass > 0 } }`
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::mass"), SymbolKind::AttributeUsage, PathBuf::from("a.sysml"), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::c"), SymbolKind::AttributeUsage, PathBuf::from("a.sysml"), span));
+
+        let resolver = Resolver::new(&ws);
+        let resolved = resolver.resolve_implicit_member(&QualifiedName::new("mass"), &[QualifiedName::new("Vehicle::c"), QualifiedName::new("Vehicle")]);
+
+        assert_eq!(resolved.map(|s| &s.qualified_name), Some(&QualifiedName::new("Vehicle::mass")));
+    }
+
+    #[test]
+    fn a_constraint_expression_resolves_an_inherited_sibling_attribute_by_bare_name() {
+        let mut ws = Workspace::default();
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        // `part def Car { attribute mass : Real; } part def Vehicle :> Car { constraint { mass > 0 } }`
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Car::mass"), SymbolKind::AttributeUsage, PathBuf::from("a.sysml"), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::c"), SymbolKind::AttributeUsage, PathBuf::from("a.sysml"), span));
+        ws.relationships.add_edge(crate::semantic::relationship_graph::RelationshipKind::Specialization, QualifiedName::new("Vehicle"), QualifiedName::new("Car"));
+
+        let resolver = Resolver::new(&ws);
+
+        // Joining the literal scope can't find an inherited member...
+        let (literal, _) = resolver.resolve_with_trace(&QualifiedName::new("mass"), &[QualifiedName::new("Vehicle::c"), QualifiedName::new("Vehicle")]);
+        assert!(literal.is_none());
+
+        // ...but the implicit-member lookup walks the specialization chain.
+        let resolved = resolver.resolve_implicit_member(&QualifiedName::new("mass"), &[QualifiedName::new("Vehicle::c"), QualifiedName::new("Vehicle")]);
+        assert_eq!(resolved.map(|s| &s.qualified_name), Some(&QualifiedName::new("Car::mass")));
+    }
+}
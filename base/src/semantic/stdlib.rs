@@ -0,0 +1,61 @@
+//! Synthetic symbols for the SysML/KerML standard library primitives, used
+//! as a goto-definition fallback when a name isn't declared anywhere in
+//! the loaded workspace.
+
+use std::path::Path;
+
+use crate::semantic::qualified_name::QualifiedName;
+use crate::semantic::symbol::{Symbol, SymbolKind};
+use crate::span::{Position, Span};
+
+/// The virtual file goto-definition points at for stdlib primitives, since
+/// `base/sysml.library` isn't necessarily loaded into every workspace.
+pub const SCALAR_VALUES_FILE: &str = "ScalarValues.kerml";
+
+const PRIMITIVE_TYPES: &[&str] = &["Boolean", "Integer", "Natural", "Positive", "Rational", "Real", "String"];
+
+/// Looks up `name` among the built-in primitive types, returning a
+/// synthetic declaration symbol if it matches one.
+pub fn lookup_primitive(name: &QualifiedName) -> Option<Symbol> {
+    let simple = name.simple_name();
+    PRIMITIVE_TYPES.iter().find(|&&p| p == simple).map(|_| primitive_symbol(simple))
+}
+
+/// Every built-in primitive type as a synthetic declaration symbol, for
+/// offering them as completion candidates (e.g. after a specialization
+/// operator on a `part def`) without resolving each one by name first.
+pub fn list_primitives() -> Vec<Symbol> {
+    PRIMITIVE_TYPES.iter().map(|&p| primitive_symbol(p)).collect()
+}
+
+fn primitive_symbol(name: &str) -> Symbol {
+    Symbol::new(
+        QualifiedName::new(name),
+        SymbolKind::PartDefinition,
+        Path::new(SCALAR_VALUES_FILE).to_path_buf(),
+        Span::new(Position::new(0, 0), Position::new(0, name.len() as u32)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_known_primitive_types() {
+        let symbol = lookup_primitive(&QualifiedName::new("Integer")).unwrap();
+        assert_eq!(symbol.file, Path::new(SCALAR_VALUES_FILE));
+    }
+
+    #[test]
+    fn does_not_resolve_unknown_names() {
+        assert!(lookup_primitive(&QualifiedName::new("Vehicle")).is_none());
+    }
+
+    #[test]
+    fn lists_every_primitive_type_exactly_once() {
+        let names: Vec<_> = list_primitives().into_iter().map(|s| s.qualified_name).collect();
+        assert_eq!(names.len(), PRIMITIVE_TYPES.len());
+        assert!(names.contains(&QualifiedName::new("Integer")));
+    }
+}
@@ -0,0 +1,57 @@
+//! The semantic-layer representation of a named model element.
+
+use std::path::PathBuf;
+
+use crate::semantic::qualified_name::QualifiedName;
+use crate::span::Span;
+
+/// The SysML v2 / KerML construct a [`Symbol`] was declared by. Defined in
+/// the `ast` module, since it's a syntactic classification rather than a
+/// semantic one; re-exported here so existing `semantic::symbol::SymbolKind`
+/// paths keep working.
+pub use crate::ast::SymbolKind;
+
+/// A named model element resolved by the semantic layer.
+///
+/// `Symbol`s are immutable once built; cross-references live in the
+/// [`Workspace`](crate::workspace::Workspace) and its
+/// `RelationshipGraph`, never on the symbol itself.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct Symbol {
+    pub qualified_name: QualifiedName,
+    pub kind: SymbolKind,
+    pub file: PathBuf,
+    /// Span covering the declaring name itself (for goto-definition).
+    pub decl_span: Span,
+}
+
+/// An opaque, stable reference to a [`Symbol`] in a
+/// [`Workspace`](crate::workspace::Workspace) — assigned once at
+/// [`Workspace::insert_symbol`](crate::workspace::Workspace::insert_symbol)
+/// and never reused, unlike a `Vec` index (which shifts whenever an
+/// unrelated file's symbols are removed). Opaque on purpose: callers
+/// compare and store ids, never construct or inspect one directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct SymbolId(pub(crate) u64);
+
+impl Symbol {
+    pub fn new(qualified_name: QualifiedName, kind: SymbolKind, file: PathBuf, decl_span: Span) -> Self {
+        Self { qualified_name, kind, file, decl_span }
+    }
+
+    pub fn name(&self) -> &str {
+        self.qualified_name.simple_name()
+    }
+
+    /// The source range of the declaring name, as used by hover,
+    /// goto-definition, and the workspace's position-to-symbol index.
+    pub fn range(&self) -> Span {
+        self.decl_span
+    }
+
+    pub fn contains(&self, position: crate::span::Position) -> bool {
+        self.decl_span.contains(position)
+    }
+}
@@ -0,0 +1,62 @@
+//! Per-file interval index over symbol spans, so position-based lookups
+//! (hover, definition, etc.) don't need a linear scan of every symbol in
+//! the workspace.
+
+use crate::span::{Position, Span};
+
+/// Spans for a single file, sorted by start position, with ties broken by
+/// widest span first so [`SymbolIndex::query`]'s backward scan reaches the
+/// innermost enclosing declaration first when spans nest (e.g. a usage
+/// inside a definition).
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct SymbolIndex {
+    entries: Vec<(Span, usize)>,
+}
+
+impl SymbolIndex {
+    pub fn build(spans: impl IntoIterator<Item = (Span, usize)>) -> Self {
+        let mut entries: Vec<_> = spans.into_iter().collect();
+        entries.sort_by_key(|(span, _)| (span.start, std::cmp::Reverse(span_width(span))));
+        Self { entries }
+    }
+
+    /// Approximate heap usage of `entries`'s backing allocation, used by
+    /// [`crate::workspace::Workspace::estimated_memory_bytes`].
+    pub fn estimated_memory_bytes(&self) -> usize {
+        self.entries.capacity() * std::mem::size_of::<(Span, usize)>()
+    }
+
+    /// The symbol index (into the original slice passed to [`build`]) of
+    /// the innermost span containing `position`, if any.
+    pub fn query(&self, position: Position) -> Option<usize> {
+        // Every entry starting at or before `position` is a candidate;
+        // `partition_point` finds the boundary in O(log n).
+        let boundary = self.entries.partition_point(|(span, _)| span.start <= position);
+        self.entries[..boundary]
+            .iter()
+            .rev()
+            .find(|(span, _)| span.contains(position))
+            .map(|(_, idx)| *idx)
+    }
+}
+
+fn span_width(span: &Span) -> (u32, u32) {
+    (span.end.line - span.start.line, span.end.column.saturating_sub(span.start.column))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_returns_innermost_symbol_when_spans_nest() {
+        let outer = Span::new(Position::new(0, 0), Position::new(10, 0));
+        let inner = Span::new(Position::new(2, 4), Position::new(2, 20));
+        let index = SymbolIndex::build([(outer, 0), (inner, 1)]);
+
+        assert_eq!(index.query(Position::new(2, 10)), Some(1));
+        assert_eq!(index.query(Position::new(5, 0)), Some(0));
+        assert_eq!(index.query(Position::new(20, 0)), None);
+    }
+}
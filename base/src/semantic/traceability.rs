@@ -0,0 +1,68 @@
+//! Requirement traceability: resolving a requirement's `subject` and
+//! `about` targets so they can be exported as a trace matrix.
+
+use crate::semantic::qualified_name::QualifiedName;
+use crate::semantic::relationship_graph::RelationshipKind;
+use crate::semantic::symbol::SymbolKind;
+use crate::workspace::Workspace;
+
+/// One row of a requirement traceability export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceabilityRow {
+    pub requirement: QualifiedName,
+    pub subject: Option<QualifiedName>,
+    pub about: Vec<QualifiedName>,
+}
+
+impl Workspace {
+    /// Resolves `subject`/`about` targets for every requirement in the
+    /// workspace, skipping targets that don't resolve to a known symbol.
+    pub fn requirement_traceability(&self) -> Vec<TraceabilityRow> {
+        self.symbols()
+            .iter()
+            .filter(|s| matches!(s.kind, SymbolKind::RequirementDefinition | SymbolKind::RequirementUsage))
+            .map(|req| {
+                let subject = self
+                    .relationships
+                    .edges(RelationshipKind::Subject, &req.qualified_name)
+                    .first()
+                    .filter(|t| self.symbol_by_qualified_name(t).is_some())
+                    .cloned();
+                let about = self
+                    .relationships
+                    .edges(RelationshipKind::About, &req.qualified_name)
+                    .iter()
+                    .filter(|t| self.symbol_by_qualified_name(t).is_some())
+                    .cloned()
+                    .collect();
+                TraceabilityRow { requirement: req.qualified_name.clone(), subject, about }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::semantic::symbol::Symbol;
+    use crate::span::{Position, Span};
+    use std::path::PathBuf;
+
+    #[test]
+    fn resolves_subject_and_about_targets_for_a_requirement() {
+        let file = PathBuf::from("Reqs.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("R1"), SymbolKind::RequirementDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::PartDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Engine"), SymbolKind::PartDefinition, file, span));
+        ws.relationships.add_edge(RelationshipKind::Subject, QualifiedName::new("R1"), QualifiedName::new("Vehicle"));
+        ws.relationships.add_edge(RelationshipKind::About, QualifiedName::new("R1"), QualifiedName::new("Engine"));
+
+        let rows = ws.requirement_traceability();
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].subject, Some(QualifiedName::new("Vehicle")));
+        assert_eq!(rows[0].about, vec![QualifiedName::new("Engine")]);
+    }
+}
@@ -0,0 +1,95 @@
+//! Source location primitives shared across the parser, semantic layer, and tooling.
+
+/// A zero-based line/column position within a single document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct Position {
+    pub line: u32,
+    pub column: u32,
+}
+
+impl Position {
+    pub fn new(line: u32, column: u32) -> Self {
+        Self { line, column }
+    }
+}
+
+/// A half-open `[start, end)` range within a single document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Span {
+    pub fn new(start: Position, end: Position) -> Self {
+        Self { start, end }
+    }
+
+    /// Whether `pos` falls within this span, treating the end as exclusive.
+    pub fn contains(&self, pos: Position) -> bool {
+        pos >= self.start && pos < self.end
+    }
+
+    /// Whether this span and `other` share any position, treating both
+    /// ends as exclusive.
+    pub fn overlaps(&self, other: &Span) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+}
+
+/// Resolves `span` to its start/end line/column pair, for embedders (the
+/// CLI, JSON output) that want to report a location without depending on
+/// `lsp_types`. Spans in this crate are already tracked in line/column
+/// terms rather than byte offsets, so this is a conversion in name only —
+/// it exists so call sites have one stable, documented entry point instead
+/// of reaching into `Span`'s fields directly, and so a future byte-offset
+/// span representation could swap in behind the same signature.
+pub fn span_to_line_col(span: Span) -> (Position, Position) {
+    (span.start, span.end)
+}
+
+/// Renders `span` as `start_line:start_column-end_line:end_column`, 1-based
+/// for human display (editors and compilers number lines/columns from 1).
+pub fn format_span(span: Span) -> String {
+    let (start, end) = span_to_line_col(span);
+    format!("{}:{}-{}:{}", start.line + 1, start.column + 1, end.line + 1, end.column + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_span_renders_one_based_line_and_column() {
+        let span = Span::new(Position::new(0, 0), Position::new(0, 5));
+        assert_eq!(format_span(span), "1:1-1:6");
+    }
+
+    #[test]
+    fn format_span_handles_a_span_on_the_last_line_of_multi_line_content() {
+        let content = "part def Vehicle {\n    part engine;\n    part wheels;\n}\n";
+        let last_line = content.lines().count() as u32 - 1;
+        let span = Span::new(Position::new(last_line, 0), Position::new(last_line, 1));
+
+        assert_eq!(format_span(span), format!("{}:1-{}:2", last_line + 1, last_line + 1));
+    }
+
+    #[test]
+    fn overlaps_is_true_for_intersecting_spans_and_false_for_disjoint_or_merely_adjacent_ones() {
+        let a = Span::new(Position::new(0, 0), Position::new(0, 10));
+        let b = Span::new(Position::new(0, 5), Position::new(0, 15));
+        let c = Span::new(Position::new(0, 10), Position::new(0, 20));
+
+        assert!(a.overlaps(&b));
+        assert!(b.overlaps(&a));
+        assert!(!a.overlaps(&c), "touching at a single boundary point isn't an overlap");
+    }
+
+    #[test]
+    fn span_to_line_col_round_trips_the_original_positions() {
+        let span = Span::new(Position::new(2, 4), Position::new(3, 0));
+        assert_eq!(span_to_line_col(span), (span.start, span.end));
+    }
+}
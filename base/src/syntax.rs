@@ -0,0 +1,96 @@
+//! Language resolution: which of SysML v2 or KerML a document is written
+//! in, decided by extension first and by content-sniffing second. The
+//! single entry point callers (LSP, CLI) should route through instead of
+//! each re-deriving its own extension check.
+
+use std::path::Path;
+
+/// The textual language a document was written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    KerML,
+    SysML,
+}
+
+/// Maps a document path to its [`Language`] by extension, or `None` for
+/// anything unrecognized (including no extension at all, where
+/// [`detect_language`] is the fallback).
+pub fn language_for_path(path: &Path) -> Option<Language> {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("kerml") => Some(Language::KerML),
+        Some("sysml") => Some(Language::SysML),
+        _ => None,
+    }
+}
+
+/// KerML-specific keywords that don't appear in SysML's surface syntax,
+/// used to disambiguate a `standard library package` whose extension
+/// didn't say which grammar it's written in.
+const KERML_KEYWORDS: [&str; 3] = ["classifier ", "metaclass ", "datatype "];
+
+/// Sniffs `content` for a [`Language`], for files with an ambiguous or
+/// missing extension. Standard library modules (both KerML's and
+/// SysML's) open with `standard library package`; from there, the
+/// presence of a KerML-only keyword like `classifier` or `datatype`
+/// settles it, defaulting to SysML otherwise. Content that doesn't even
+/// look like a standard library module gives no signal either way.
+pub fn detect_language(content: &str) -> Option<Language> {
+    if !content.trim_start().starts_with("standard library package") {
+        return None;
+    }
+    if KERML_KEYWORDS.iter().any(|keyword| content.contains(keyword)) {
+        Some(Language::KerML)
+    } else {
+        Some(Language::SysML)
+    }
+}
+
+/// Resolves the [`Language`] for a document: extension first, falling
+/// back to [`detect_language`]'s content-sniffing when the extension
+/// doesn't say. This is the entry point directory loading and the LSP
+/// should dispatch through rather than scattering their own extension
+/// checks — no per-language grammar is wired in yet, so resolving the
+/// language is as far as this commits to today.
+pub fn parse(content: &str, path: &Path) -> Option<Language> {
+    language_for_path(path).or_else(|| detect_language(content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn extension_resolves_kerml_and_sysml_and_rejects_anything_else() {
+        assert_eq!(language_for_path(Path::new("Vehicle.kerml")), Some(Language::KerML));
+        assert_eq!(language_for_path(Path::new("Vehicle.sysml")), Some(Language::SysML));
+        assert_eq!(language_for_path(Path::new("Vehicle.txt")), None);
+    }
+
+    #[test]
+    fn content_sniffing_finds_kerml_keywords_in_a_standard_library_module() {
+        let content = "standard library package Base {\n  classifier Anything;\n}";
+        assert_eq!(detect_language(content), Some(Language::KerML));
+    }
+
+    #[test]
+    fn content_sniffing_defaults_a_standard_library_module_without_kerml_keywords_to_sysml() {
+        let content = "standard library package ISQ {\n  attribute def LengthValue;\n}";
+        assert_eq!(detect_language(content), Some(Language::SysML));
+    }
+
+    #[test]
+    fn content_sniffing_gives_no_signal_for_content_that_isn_t_a_standard_library_module() {
+        assert_eq!(detect_language("part def Vehicle { }"), None);
+    }
+
+    #[test]
+    fn parse_prefers_the_extension_and_falls_back_to_content_sniffing() {
+        assert_eq!(parse("classifier Anything;", Path::new("Base.kerml")), Some(Language::KerML));
+
+        let content = "standard library package Base {\n  datatype ScalarValue;\n}";
+        assert_eq!(parse(content, Path::new("Base")), Some(Language::KerML));
+
+        assert_eq!(parse("whatever", Path::new("Base.txt")), None);
+    }
+}
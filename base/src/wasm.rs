@@ -0,0 +1,75 @@
+//! WASM bindings for the parser and semantic analyzer, gated behind the
+//! `wasm` feature (declared in `Cargo.toml` under `[target.'cfg(target_arch
+//! = "wasm32")'.dependencies]`, and implying `semantic` since
+//! [`diagnostics_for_source`] goes through [`crate::embed`])
+//! so the native CLI/LSP build never pulls in `wasm-bindgen`.
+
+use wasm_bindgen::prelude::*;
+
+use crate::embed::{analyze_str, AnalyzeOptions};
+use crate::format::{normalize_indentation, FormatOptions};
+use crate::format_span;
+use crate::parser::recovery::scan_unterminated_delimiters;
+use crate::syntax::{detect_language, Language};
+
+/// Normalizes a source string's indentation using spaces, for use from
+/// JavaScript (e.g. a browser-based playground) without a native toolchain.
+#[wasm_bindgen]
+pub fn format_source(source: &str) -> String {
+    normalize_indentation(source, &FormatOptions::default())
+}
+
+/// Parses `source`, returning one `"<span>: <message>"` line per recovered
+/// syntax error — today, just an unterminated block comment or string
+/// literal ([`scan_unterminated_delimiters`]; there's no full grammar
+/// wired into this tree yet, see [`crate::parser::recovery`]'s module
+/// docs). An empty result means `source` has no recoverable syntax error
+/// at this level.
+#[wasm_bindgen]
+pub fn parse_str(source: &str) -> Vec<String> {
+    scan_unterminated_delimiters(source).into_iter().map(|error| format!("{}: {}", format_span(error.span), error.message)).collect()
+}
+
+/// Single-file symbol/diagnostics query for `source`, without the caller
+/// needing to construct and populate a full `Workspace`. The language is
+/// detected from content ([`detect_language`], defaulting to SysML when
+/// ambiguous, since a bare string has no file extension to resolve it
+/// by), then analyzed via [`analyze_str`] — the same minimal,
+/// `std::fs`/tokio-free entry point lightweight editor integrations
+/// already use natively. One `"<span>: <message>"` line per finding.
+#[wasm_bindgen]
+pub fn diagnostics_for_source(source: &str) -> Vec<String> {
+    let language = detect_language(source).unwrap_or(Language::SysML);
+    analyze_str(source, language, &AnalyzeOptions::default()).into_iter().map(|d| format!("{}: {}", format_span(d.span), d.message)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_str_reports_an_unterminated_string_literal() {
+        let errors = parse_str("part def Vehicle { doc \"unterminated");
+
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("unterminated string literal"));
+    }
+
+    #[test]
+    fn parse_str_is_empty_for_source_with_no_recoverable_syntax_error() {
+        assert!(parse_str("part def Vehicle;").is_empty());
+    }
+
+    #[test]
+    fn diagnostics_for_source_reports_an_unresolved_type_reference() {
+        let diagnostics = diagnostics_for_source("attribute mass : Mss;");
+
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].contains("Mss"));
+    }
+
+    #[test]
+    fn diagnostics_for_source_is_empty_for_content_with_no_recognizable_declaration() {
+        assert!(diagnostics_for_source("package Vehicle { }").is_empty());
+    }
+}
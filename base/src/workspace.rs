@@ -0,0 +1,1896 @@
+//! A loaded collection of SysML/KerML documents and their resolved symbols.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::semantic::qualified_name::QualifiedName;
+use crate::semantic::relationship_graph::RelationshipGraph;
+use crate::semantic::resolution_cache::ResolutionCache;
+use crate::semantic::symbol::{Symbol, SymbolId, SymbolKind};
+use crate::semantic::symbol_index::SymbolIndex;
+use crate::span::Position;
+
+/// All documents loaded for a single analysis session, plus their resolved
+/// symbols. Constructed via [`Workspace::load_dir`] or incrementally via the
+/// LSP document lifecycle.
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct Workspace {
+    symbols: Vec<Symbol>,
+    /// `self.symbol_ids[i]` is the stable [`SymbolId`] of `self.symbols[i]`,
+    /// assigned once at [`Workspace::insert_symbol`] and never reused —
+    /// unlike the `Vec` index itself, which shifts whenever an unrelated
+    /// file's symbols are removed via [`Workspace::remove_file`].
+    symbol_ids: Vec<SymbolId>,
+    /// The next [`SymbolId`] to assign, monotonically increasing so ids
+    /// are never reused even after a symbol is removed.
+    next_symbol_id: u64,
+    /// Reverse lookup from a [`SymbolId`] to its current index in
+    /// `symbols`, rebuilt alongside `by_qualified_name`/`kind_index`
+    /// whenever `symbols` changes structurally.
+    id_index: HashMap<SymbolId, usize>,
+    by_qualified_name: HashMap<QualifiedName, usize>,
+    /// Every symbol's index in `symbols`, bucketed by [`SymbolKind`], so
+    /// "find all definitions of kind" queries (e.g. "all requirements")
+    /// start from their bucket instead of scanning every symbol. Kept in
+    /// sync wherever `symbols` changes, the same way `by_qualified_name` is.
+    kind_index: HashMap<SymbolKind, Vec<usize>>,
+    /// Position-to-symbol index, one per file, rebuilt whenever a file's
+    /// symbols change so hover/definition/etc. don't linearly scan.
+    index_by_file: HashMap<PathBuf, SymbolIndex>,
+    loaded_files: BTreeSet<PathBuf>,
+    pub relationships: RelationshipGraph,
+    /// Qualified names annotated with a `@Deprecated`-style metadata usage.
+    /// Kept separate from `Symbol` since deprecation is a metadata
+    /// annotation, not an intrinsic property of the declaration.
+    deprecated: std::collections::HashSet<QualifiedName>,
+    /// Qualified names declared `public` (or otherwise exported), so a lint
+    /// like the unused-definition check can tell "nothing in this
+    /// workspace uses it" apart from "nothing can use it, it's private".
+    exported: std::collections::HashSet<QualifiedName>,
+    /// Files whose content begins with `standard library package`, loaded
+    /// through the ordinary [`Workspace::load_dir`] path rather than a
+    /// dedicated stdlib loader. Treated the same as real stdlib for rename
+    /// refusal and unused-definition exemption.
+    library_files: std::collections::HashSet<PathBuf>,
+    parse_errors: HashMap<PathBuf, Vec<crate::parser::RecoveredError>>,
+    /// The `Target` named by each `alias MyAlias for Target;` declaration,
+    /// kept separate from `Symbol` since an alias's target is a reference
+    /// rather than an intrinsic property of the alias's own declaration.
+    alias_targets: HashMap<QualifiedName, QualifiedName>,
+    pub multiplicities: crate::semantic::multiplicity::MultiplicityTable,
+    pub feature_values: crate::semantic::feature_value::FeatureValueTable,
+    pub doc_comments: crate::semantic::doc_comment::DocCommentTable,
+    /// Caches [`Workspace::goto_definition_by_name`]'s by-qualified-name
+    /// lookup, invalidated per-file in [`Workspace::remove_file`] (and so,
+    /// transitively, [`Workspace::rename_file`]) rather than cleared wholesale.
+    #[cfg_attr(feature = "persist", serde(skip))]
+    resolution_cache: ResolutionCache,
+    /// Backs [`Workspace::is_referenced`] (used by
+    /// [`crate::diagnostics::check_unused_definitions`]). Built lazily on
+    /// first use, then kept current by [`Workspace::remove_file`]/
+    /// [`Workspace::refresh_references_for_file`] instead of being rebuilt
+    /// from every symbol on every diagnostics pass. `RefCell` since the
+    /// lazy build happens behind read-only accessors.
+    #[cfg_attr(feature = "persist", serde(skip))]
+    reference_collector: std::cell::RefCell<Option<crate::diagnostics::ReferenceCollector>>,
+}
+
+impl Workspace {
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    /// An immutable, `Arc`-backed [`WorkspaceSnapshot`] of the current
+    /// symbols and relationships. A query handler holding a snapshot (not
+    /// a borrow of the live `Workspace`) can keep running after a
+    /// subsequent edit without observing it — useful for offloading a
+    /// long-running read (workspace symbols, references) to a blocking
+    /// thread without holding up the next `did_change`.
+    pub fn snapshot(&self) -> WorkspaceSnapshot {
+        WorkspaceSnapshot {
+            symbols: std::sync::Arc::new(self.symbols.clone()),
+            relationships: std::sync::Arc::new(self.relationships.clone()),
+        }
+    }
+
+    pub fn insert_symbol(&mut self, symbol: Symbol) {
+        let idx = self.symbols.len();
+        let id = SymbolId(self.next_symbol_id);
+        self.next_symbol_id += 1;
+        self.by_qualified_name.insert(symbol.qualified_name.clone(), idx);
+        self.kind_index.entry(symbol.kind).or_default().push(idx);
+        self.id_index.insert(id, idx);
+        let file = symbol.file.clone();
+        self.symbols.push(symbol);
+        self.symbol_ids.push(id);
+        self.rebuild_index_for(&file);
+    }
+
+    /// Resolves a stable [`SymbolId`] to its current declaration, or
+    /// `None` if that symbol has since been removed (e.g. its file was
+    /// reloaded). `Workspace` plays the role a dedicated `SymbolTable`
+    /// would in this tree — it already owns the symbol storage and the
+    /// by-name index — so this and [`Workspace::id_of_qualified_name`]
+    /// live here rather than on a separate type.
+    pub fn get(&self, id: SymbolId) -> Option<&Symbol> {
+        self.id_index.get(&id).map(|&idx| &self.symbols[idx])
+    }
+
+    /// The stable [`SymbolId`] currently naming `name`, if any.
+    pub fn id_of_qualified_name(&self, name: &QualifiedName) -> Option<SymbolId> {
+        let idx = *self.by_qualified_name.get(name)?;
+        self.symbol_ids.get(idx).copied()
+    }
+
+    /// Every symbol of `kind`, e.g. every `RequirementUsage` for a
+    /// workspace-wide "find all requirements" query, via the bucketed kind
+    /// index rather than a scan over `symbols`.
+    pub fn all_of_kind(&self, kind: SymbolKind) -> Vec<&Symbol> {
+        self.kind_index.get(&kind).map(Vec::as_slice).unwrap_or(&[]).iter().map(|&idx| &self.symbols[idx]).collect()
+    }
+
+    pub fn symbol_by_qualified_name(&self, name: &QualifiedName) -> Option<&Symbol> {
+        self.by_qualified_name.get(name).map(|&idx| &self.symbols[idx])
+    }
+
+    /// Rebuilds the position-to-symbol index for `file` from its current
+    /// symbols. Called after any insert/removal touching that file, and
+    /// again whenever the file is reparsed.
+    pub fn rebuild_index_for(&mut self, file: &Path) {
+        let spans = self
+            .symbols
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.file == file)
+            .map(|(idx, s)| (s.range(), idx));
+        self.index_by_file.insert(file.to_path_buf(), SymbolIndex::build(spans));
+    }
+
+    /// Resolve the symbol declared or referenced at `position` in `file` in
+    /// O(log n) via the per-file [`SymbolIndex`], used by hover,
+    /// goto-definition and LSIF.
+    pub fn symbol_at(&self, file: &Path, position: Position) -> Option<&Symbol> {
+        let idx = self.index_by_file.get(file)?.query(position)?;
+        self.symbols.get(idx)
+    }
+
+    /// Markdown hover content for the symbol at `position`, or `None` if
+    /// nothing resolves there.
+    pub fn hover(&self, file: &Path, position: Position) -> Option<String> {
+        self.hover_with_options(file, position, &HoverOptions::default())
+    }
+
+    /// As [`Self::hover`], but with `options.debug` honored: when set, the
+    /// matched symbol's kind and declaration span are appended to the
+    /// hover text, for contributors diagnosing why a feature doesn't fire
+    /// on some construct. Strictly opt-in — a normal hover (`debug: false`,
+    /// the default) never shows this.
+    pub fn hover_with_options(&self, file: &Path, position: Position, options: &HoverOptions) -> Option<String> {
+        let symbol = self.symbol_at(file, position)?;
+        let text = self.hover_text(symbol)?;
+        let text = if options.debug {
+            format!("{text}\n\n---\n`debugHover`: kind = `{:?}`, span = {}", symbol.kind, crate::format_span(symbol.range()))
+        } else {
+            text
+        };
+        Some(self.with_deprecation_notice(&symbol.qualified_name, text))
+    }
+
+    fn hover_text(&self, symbol: &Symbol) -> Option<String> {
+        match symbol.kind {
+            SymbolKind::EnumeratedValue => {
+                let owner = symbol.qualified_name.parent().map(|p| p.to_string()).unwrap_or_default();
+                Some(format!("**{}**\n\nenumerated value of `{}`", symbol.qualified_name, owner))
+            }
+            SymbolKind::Package => {
+                let (mut definitions, mut usages, mut subpackages) = (0, 0, 0);
+                for member in self.children_of(&symbol.qualified_name) {
+                    if member.kind == SymbolKind::Package {
+                        subpackages += 1;
+                    } else if is_definition_kind(member.kind) {
+                        definitions += 1;
+                    } else if is_usage_kind(member.kind) {
+                        usages += 1;
+                    }
+                }
+                let visibility = if self.is_exported(&symbol.qualified_name) { "public" } else { "private" };
+                let mut text = format!(
+                    "**{}**\n\n`Package` ({visibility})\n\n{definitions} definition(s), {usages} usage(s), {subpackages} subpackage(s)",
+                    symbol.qualified_name
+                );
+                if let Some(doc) = self.doc_comments.get(&symbol.qualified_name) {
+                    text.push_str(&format!("\n\n---\n{doc}"));
+                }
+                Some(text)
+            }
+            SymbolKind::SnapshotUsage | SymbolKind::TimesliceUsage => {
+                let portion_kind = if symbol.kind == SymbolKind::SnapshotUsage { "snapshot" } else { "timeslice" };
+                let owner = self
+                    .relationships
+                    .edges(crate::semantic::relationship_graph::RelationshipKind::PortionOf, &symbol.qualified_name)
+                    .first()
+                    .map(QualifiedName::to_string);
+                match owner {
+                    Some(owner) => Some(format!("**{}**\n\n{portion_kind} of `{owner}`", symbol.qualified_name)),
+                    None => Some(format!("**{}**\n\n{portion_kind}", symbol.qualified_name)),
+                }
+            }
+            _ => {
+                let mut text = format!("**{}**\n\n`{:?}`", symbol.qualified_name, symbol.kind);
+                let chain = self.relationships.specialization_chain(&symbol.qualified_name);
+                if chain.len() > 1 {
+                    let chain_str = chain.iter().map(|name| self.hover_link(name)).collect::<Vec<_>>().join(" :> ");
+                    text.push_str(&format!("\n\nspecializes: {chain_str}"));
+                }
+                if let Some(ty) = self.relationships.edges(crate::semantic::relationship_graph::RelationshipKind::Typing, &symbol.qualified_name).first() {
+                    text.push_str(&format!("\n\ntyped by: {}", self.hover_link(ty)));
+                    if self.multiplicities.is_conjugated(&symbol.qualified_name) {
+                        text.push_str(" (conjugated)");
+                    }
+                }
+                if let Some(original) = self.relationships.edges(crate::semantic::relationship_graph::RelationshipKind::Redefinition, &symbol.qualified_name).first() {
+                    text.push_str(&format!("\n\nredefines: {}", self.hover_link(original)));
+                }
+                if let Some(value) = self.feature_values.get(&symbol.qualified_name) {
+                    text.push_str(&format!("\n\nvalue: {}", value.render()));
+                }
+                if let Some(multiplicity) = self.effective_multiplicity(&symbol.qualified_name) {
+                    text.push_str(&format!("\n\nmultiplicity: `{}`", multiplicity.render()));
+                }
+                Some(text)
+            }
+        }
+    }
+
+    fn with_deprecation_notice(&self, name: &QualifiedName, text: String) -> String {
+        if self.is_deprecated(name) {
+            format!("⚠️ **Deprecated**\n\n{text}")
+        } else {
+            text
+        }
+    }
+
+    /// Renders `name` as a clickable Markdown link to its declaration's
+    /// `file://` location (one-based line, matching editors' `#Lnn`
+    /// fragment convention) when it resolves to a symbol loaded in this
+    /// workspace, or as plain code text otherwise — e.g. a stdlib primitive
+    /// with no file of its own, or a name that doesn't resolve at all.
+    fn hover_link(&self, name: &QualifiedName) -> String {
+        match self.symbol_by_qualified_name(name) {
+            Some(target) => format!("[{name}](file://{}#L{})", target.file.display(), target.range().start.line + 1),
+            None => format!("`{name}`"),
+        }
+    }
+
+    /// The declaration site of the symbol referenced at `position`.
+    pub fn goto_definition(&self, file: &Path, position: Position) -> Option<&Symbol> {
+        self.symbol_at(file, position)
+    }
+
+    /// Like [`Workspace::goto_definition`], but when the symbol at
+    /// `position` is an [`Alias`](SymbolKind::Alias), includes its target
+    /// alongside the alias declaration itself, matching the LSP
+    /// `Location[]` response an editor expects for "jump to the thing this
+    /// name stands for" as well as "jump to where the alias is declared".
+    pub fn goto_definition_multi(&self, file: &Path, position: Position) -> Vec<&Symbol> {
+        let Some(symbol) = self.symbol_at(file, position) else { return Vec::new() };
+        match self.alias_target(&symbol.qualified_name).and_then(|target| self.symbol_by_qualified_name(target)) {
+            Some(target) => vec![symbol, target],
+            None => vec![symbol],
+        }
+    }
+
+    /// Records that `alias` names `target`, e.g. from `alias MyAlias for
+    /// Target;`.
+    pub fn mark_alias(&mut self, alias: QualifiedName, target: QualifiedName) {
+        self.alias_targets.insert(alias, target);
+    }
+
+    pub fn alias_target(&self, alias: &QualifiedName) -> Option<&QualifiedName> {
+        self.alias_targets.get(alias)
+    }
+
+    /// Records `usage`'s type, e.g. from `port p : Port;` or a conjugated
+    /// `port p : ~Port;`. A leading `~` marks the usage as conjugated (see
+    /// [`crate::semantic::multiplicity::MultiplicityTable::mark_conjugated`])
+    /// and is stripped before recording the `Typing` edge, so resolution,
+    /// goto-definition and hover's "typed by" link all reach `Port` itself
+    /// rather than a dangling `~Port` name.
+    pub fn record_typing(&mut self, usage: QualifiedName, type_ref: &str) {
+        let type_ref = type_ref.trim();
+        let conjugated = type_ref.starts_with('~');
+        let ty = QualifiedName::new(type_ref.trim_start_matches('~').trim());
+        if conjugated {
+            self.multiplicities.mark_conjugated(usage.clone());
+        }
+        self.relationships.add_edge(crate::semantic::relationship_graph::RelationshipKind::Typing, usage, ty);
+    }
+
+    /// The outline `detail` string for an alias, e.g. `"for Target"`.
+    pub fn alias_detail(&self, alias: &QualifiedName) -> Option<String> {
+        self.alias_target(alias).map(|target| format!("for {target}"))
+    }
+
+    /// Resolves `name` to its declaration, falling back to the synthetic
+    /// stdlib primitive-type symbols (`Integer`, `Boolean`, ...) when
+    /// nothing in the loaded workspace declares it. Used by
+    /// goto-definition on a type reference that isn't locally declared.
+    ///
+    /// The workspace-declared half of this lookup goes through
+    /// [`Self::resolution_cache`], so a repeated goto-definition on the
+    /// same name (common when hovering/jumping around one type-heavy file)
+    /// skips the by-qualified-name lookup entirely after the first hit.
+    pub fn goto_definition_by_name(&self, name: &QualifiedName) -> Option<Symbol> {
+        self.resolution_cache.resolve(self, name).or_else(|| crate::semantic::stdlib::lookup_primitive(name))
+    }
+
+    /// Whether some relationship edge in the workspace targets `name`, used
+    /// by [`crate::diagnostics::check_unused_definitions`] to tell a real
+    /// use apart from a definition nobody points at.
+    ///
+    /// Backed by `self.reference_collector`, built from every symbol on
+    /// first call and kept current afterwards by [`Self::remove_file`]/
+    /// [`Self::refresh_references_for_file`] rather than rescanned here.
+    pub(crate) fn is_referenced(&self, name: &QualifiedName) -> bool {
+        self.reference_collector.borrow_mut().get_or_insert_with(|| crate::diagnostics::ReferenceCollector::build(self)).is_referenced(name)
+    }
+
+    /// Drops whatever `file` previously contributed to the reference index
+    /// backing [`Self::is_referenced`] and re-adds its current contribution
+    /// — e.g. after [`Self::rename_file`] relocates a file's symbols and
+    /// the relationship edges sourced from them are otherwise untouched. A
+    /// no-op if the index hasn't been built yet (nothing to refresh).
+    pub fn refresh_references_for_file(&self, file: &Path) {
+        if let Some(collector) = self.reference_collector.borrow_mut().as_mut() {
+            collector.remove_file(self, file);
+            collector.add_file(self, file);
+        }
+    }
+
+    /// Drops `file`'s contribution to the reference index backing
+    /// [`Self::is_referenced`], if that index has been built yet. Called
+    /// from [`Self::remove_file`] while `file`'s symbols (and the edges
+    /// sourced from them) are still present to scan.
+    fn drop_references_for_file(&self, file: &Path) {
+        if let Some(collector) = self.reference_collector.borrow_mut().as_mut() {
+            collector.remove_file(self, file);
+        }
+    }
+
+    /// All declaration/reference locations sharing the qualified name of the
+    /// symbol at `position`, including the declaration itself plus every
+    /// symbol that points at it via an `import`, a usage typing, or a
+    /// comment's `about` target (anything [`RelationshipGraph::referencing`]
+    /// reports), so references found in those positions aren't silently
+    /// dropped just because they aren't the same declaration.
+    pub fn find_references(&self, file: &Path, position: Position) -> Vec<&Symbol> {
+        let Some(target) = self.symbol_at(file, position) else { return Vec::new() };
+
+        let mut names: HashSet<QualifiedName> = HashSet::new();
+        names.insert(target.qualified_name.clone());
+        for (_, from) in self.relationships.referencing(&target.qualified_name) {
+            names.insert(from);
+        }
+
+        self.symbols.iter().filter(|s| names.contains(&s.qualified_name)).collect()
+    }
+
+    /// Records the syntax errors recovered from parsing `file`, replacing
+    /// whatever was recorded for it previously (e.g. on reparse).
+    pub fn set_parse_errors(&mut self, file: PathBuf, errors: Vec<crate::parser::RecoveredError>) {
+        self.parse_errors.insert(file, errors);
+    }
+
+    pub fn parse_errors(&self, file: &Path) -> &[crate::parser::RecoveredError] {
+        self.parse_errors.get(file).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Every recorded parse error across every file, paired with the file
+    /// it was recovered from. Used to fold parse errors into the
+    /// diagnostics pipeline alongside semantic findings, e.g.
+    /// [`crate::diagnostics::dedup_with_parse_errors`].
+    pub fn all_parse_errors(&self) -> impl Iterator<Item = (&Path, &crate::parser::RecoveredError)> {
+        self.parse_errors.iter().flat_map(|(file, errors)| errors.iter().map(move |error| (file.as_path(), error)))
+    }
+
+    /// Marks `name` as deprecated, e.g. because its declaration carries a
+    /// `@Deprecated` metadata usage.
+    pub fn mark_deprecated(&mut self, name: QualifiedName) {
+        self.deprecated.insert(name);
+    }
+
+    pub fn is_deprecated(&self, name: &QualifiedName) -> bool {
+        self.deprecated.contains(name)
+    }
+
+    /// Marks `name` as declared `public`, exempting it from the
+    /// unused-definition lint (it may be referenced from outside this
+    /// workspace).
+    pub fn mark_exported(&mut self, name: QualifiedName) {
+        self.exported.insert(name);
+    }
+
+    pub fn is_exported(&self, name: &QualifiedName) -> bool {
+        self.exported.contains(name)
+    }
+
+    /// Marks `file` as library-origin, e.g. because its content begins with
+    /// `standard library package`. Affects rename refusal and the
+    /// unused-definition lint the same way real stdlib files do, even
+    /// though the file was loaded as an ordinary workspace member.
+    pub fn mark_library_file(&mut self, file: PathBuf) {
+        self.library_files.insert(file);
+    }
+
+    pub fn is_library_file(&self, file: &Path) -> bool {
+        self.library_files.contains(file)
+            || file.file_name().and_then(|n| n.to_str()) == Some(crate::semantic::stdlib::SCALAR_VALUES_FILE)
+    }
+
+    /// Whether `name` may be renamed. Library-origin declarations (real
+    /// stdlib or a user file starting with `standard library package`) are
+    /// refused, since renaming them would desync from a library other
+    /// files outside this workspace may depend on.
+    pub fn can_rename(&self, name: &QualifiedName) -> bool {
+        match self.symbol_by_qualified_name(name) {
+            Some(symbol) => !self.is_library_file(&symbol.file),
+            None => false,
+        }
+    }
+
+    /// `name`'s own declared multiplicity if it has one, otherwise the
+    /// multiplicity inherited from whatever it redefines or subsets,
+    /// possibly declared in a different file. Cycle-safe: a redefinition
+    /// chain that loops back on itself stops instead of recursing forever.
+    pub fn effective_multiplicity(&self, name: &QualifiedName) -> Option<crate::semantic::multiplicity::Multiplicity> {
+        use crate::semantic::relationship_graph::RelationshipKind;
+
+        let mut current = name.clone();
+        let mut seen = HashSet::new();
+        seen.insert(current.clone());
+        loop {
+            if let Some(multiplicity) = self.multiplicities.multiplicity(&current) {
+                return Some(multiplicity);
+            }
+            let next = self
+                .relationships
+                .edges(RelationshipKind::Redefinition, &current)
+                .first()
+                .or_else(|| self.relationships.edges(RelationshipKind::Subsetting, &current).first())?
+                .clone();
+            if !seen.insert(next.clone()) {
+                return None;
+            }
+            current = next;
+        }
+    }
+
+    /// `id`'s specialization ancestors, direct or transitive, nearest
+    /// first, as stable [`SymbolId`]s rather than the [`QualifiedName`]s
+    /// [`RelationshipGraph::supertypes_of`] deals in — `SymbolId` is the
+    /// currency of this and the other public query APIs below, so
+    /// embedders and the LSP's type-hierarchy feature can hold onto a
+    /// result across an unrelated edit instead of re-resolving names. An
+    /// ancestor that no longer resolves to a current symbol (e.g. its file
+    /// was removed) is dropped rather than reported.
+    pub fn supertypes_of(&self, id: SymbolId, transitive: bool) -> Vec<SymbolId> {
+        let Some(symbol) = self.get(id) else { return Vec::new() };
+        self.relationships.supertypes_of(&symbol.qualified_name, transitive).iter().filter_map(|name| self.id_of_qualified_name(name)).collect()
+    }
+
+    /// The reverse of [`Self::supertypes_of`]: every id that specializes
+    /// `id`, direct or transitive, nearest first.
+    pub fn specializations_of(&self, id: SymbolId, transitive: bool) -> Vec<SymbolId> {
+        let Some(symbol) = self.get(id) else { return Vec::new() };
+        self.relationships.specializations_of(&symbol.qualified_name, transitive).iter().filter_map(|name| self.id_of_qualified_name(name)).collect()
+    }
+
+    /// Every id with an edge of any kind pointing at `id`, e.g. the usages
+    /// typed by a definition or the imports naming it. Backed by
+    /// [`RelationshipGraph::referencing`], the reverse-edge lookup
+    /// find-references is built on; this is that same query exposed
+    /// through the stable-id API the rest of this block offers.
+    pub fn references_to(&self, id: SymbolId) -> Vec<SymbolId> {
+        let Some(symbol) = self.get(id) else { return Vec::new() };
+        self.relationships.referencing(&symbol.qualified_name).into_iter().filter_map(|(_, from)| self.id_of_qualified_name(&from)).collect()
+    }
+
+    /// The `variant` members declared inside `variation`, e.g. the options
+    /// of a `variation part def Wheel { variant steel : SteelWheel; variant
+    /// alloy : AlloyWheel; }`.
+    pub fn variants_of(&self, variation: &QualifiedName) -> Vec<&Symbol> {
+        self.children_of(variation).into_iter().filter(|s| s.kind == SymbolKind::VariantUsage).collect()
+    }
+
+    /// Direct members of `scope`, e.g. the part usages and features owned
+    /// by a `part def`. Used for member-access completion after `.`.
+    pub fn children_of(&self, scope: &QualifiedName) -> Vec<&Symbol> {
+        self.symbols.iter().filter(|s| s.qualified_name.parent().as_ref() == Some(scope)).collect()
+    }
+
+    /// `scope`'s own members plus every member inherited from its
+    /// specialization ancestors (`part def Car :> Vehicle { ... }` sees
+    /// `Vehicle`'s members), possibly declared in a different file than
+    /// `scope` itself. Used for member-access completion inside a
+    /// definition body.
+    pub fn members_including_inherited(&self, scope: &QualifiedName) -> Vec<&Symbol> {
+        self.relationships.specialization_chain(scope).iter().flat_map(|ancestor| self.children_of(ancestor)).collect()
+    }
+
+    /// Resolves `name` as a member of `scope`, checking `scope`'s own
+    /// members before walking its specialization chain. Used by hover on a
+    /// bare reference to an inherited member, which resolves to its
+    /// declaration in the supertype rather than `scope`.
+    pub fn resolve_member(&self, scope: &QualifiedName, name: &str) -> Option<&Symbol> {
+        self.members_including_inherited(scope).into_iter().find(|s| s.name() == name)
+    }
+
+    /// The `return : Type;` parameter declared directly inside a `calc
+    /// def`/`calc` usage, conventionally named `return` per the grammar's
+    /// `return_parameter_member`, if one was declared.
+    pub fn return_parameter(&self, calc: &QualifiedName) -> Option<&Symbol> {
+        self.symbol_by_qualified_name(&calc.join("return"))
+    }
+
+    /// Records that `portion` (a `snapshot`/`timeslice` usage) is a portion
+    /// of `owner`, the enclosing occurrence, via a
+    /// [`PortionOf`](crate::semantic::relationship_graph::RelationshipKind::PortionOf)
+    /// edge.
+    pub fn record_portion(&mut self, portion: QualifiedName, owner: QualifiedName) {
+        self.relationships.add_edge(crate::semantic::relationship_graph::RelationshipKind::PortionOf, portion, owner);
+    }
+
+    /// Records that `view` (a [`ViewUsage`](SymbolKind::ViewUsage)) exposes
+    /// `target` via `expose <target>;`, using an
+    /// [`Expose`](crate::semantic::relationship_graph::RelationshipKind::Expose)
+    /// edge.
+    pub fn record_expose(&mut self, view: QualifiedName, target: QualifiedName) {
+        self.relationships.add_edge(crate::semantic::relationship_graph::RelationshipKind::Expose, view, target);
+    }
+
+    /// Records that `requirement` frames `concern` via `frame <concern>;`,
+    /// using a
+    /// [`Frame`](crate::semantic::relationship_graph::RelationshipKind::Frame)
+    /// edge, so navigation reaches the concern's declaration. Recorded
+    /// unconditionally, even when `concern` doesn't resolve to anything in
+    /// the workspace; [`crate::diagnostics::check_framed_concerns`] is what
+    /// flags that case, the same split `record_*`/`check_*` responsibility
+    /// [`Self::record_typing`] and [`crate::diagnostics::check_typing`] use.
+    pub fn record_frame(&mut self, requirement: QualifiedName, concern: QualifiedName) {
+        self.relationships.add_edge(crate::semantic::relationship_graph::RelationshipKind::Frame, requirement, concern);
+    }
+
+    /// The document-symbol outline for everything declared directly under
+    /// `scope`, nested per declaration. With
+    /// `options.include_exposed`, a [`ViewUsage`](SymbolKind::ViewUsage)'s
+    /// resolved `expose` targets are additionally listed as synthetic
+    /// reference children (`is_reference: true`) rather than recursed
+    /// into, since they're pointers into another namespace, not
+    /// declarations owned by the view. Off by default to avoid cluttering
+    /// the outline with every exposed member; the LSP surfaces this as the
+    /// `outlineIncludeExposed` setting.
+    pub fn document_symbols(&self, scope: &QualifiedName, options: &DocumentSymbolOptions) -> Vec<OutlineEntry> {
+        self.children_of(scope)
+            .into_iter()
+            .map(|symbol| {
+                let mut children = self.document_symbols(&symbol.qualified_name, options);
+                if options.include_exposed && symbol.kind == SymbolKind::ViewUsage {
+                    for target in self.relationships.edges(crate::semantic::relationship_graph::RelationshipKind::Expose, &symbol.qualified_name) {
+                        if let Some(resolved) = self.symbol_by_qualified_name(target) {
+                            children.push(OutlineEntry {
+                                name: resolved.name().to_string(),
+                                qualified_name: resolved.qualified_name.clone(),
+                                kind: resolved.kind,
+                                is_reference: true,
+                                children: Vec::new(),
+                            });
+                        }
+                    }
+                }
+                OutlineEntry {
+                    name: symbol.name().to_string(),
+                    qualified_name: symbol.qualified_name.clone(),
+                    kind: symbol.kind,
+                    is_reference: false,
+                    children,
+                }
+            })
+            .collect()
+    }
+
+    /// A stable, workspace-unique identifier suitable for LSIF moniker
+    /// vertices: the symbol's fully qualified name.
+    pub fn moniker(&self, file: &Path, position: Position) -> Option<String> {
+        self.symbol_at(file, position).map(|s| s.qualified_name.to_string())
+    }
+
+    /// Drops every symbol declared in `file`, e.g. when an unsaved buffer is
+    /// closed without ever having been saved to disk.
+    pub fn remove_file(&mut self, file: &Path) {
+        self.drop_references_for_file(file);
+
+        let mut kept_symbols = Vec::with_capacity(self.symbols.len());
+        let mut kept_ids = Vec::with_capacity(self.symbol_ids.len());
+        for (symbol, id) in self.symbols.drain(..).zip(self.symbol_ids.drain(..)) {
+            if symbol.file != file {
+                kept_symbols.push(symbol);
+                kept_ids.push(id);
+            }
+        }
+        self.symbols = kept_symbols;
+        self.symbol_ids = kept_ids;
+
+        self.by_qualified_name.clear();
+        self.kind_index.clear();
+        self.id_index.clear();
+        for (idx, (symbol, id)) in self.symbols.iter().zip(self.symbol_ids.iter()).enumerate() {
+            self.by_qualified_name.insert(symbol.qualified_name.clone(), idx);
+            self.kind_index.entry(symbol.kind).or_default().push(idx);
+            self.id_index.insert(*id, idx);
+        }
+        self.index_by_file.remove(file);
+        self.loaded_files.remove(file);
+        self.resolution_cache.invalidate_file(file);
+    }
+
+    /// Re-declares every symbol currently in `old` as declared in `new`
+    /// instead, e.g. when a `.sysml` file is renamed/moved on disk. A no-op
+    /// if `old` has no symbols. SysML/KerML references resolve by qualified
+    /// name rather than file path, so nothing else needs to change —
+    /// goto-definition, hover, and the position index just need to keep
+    /// pointing at the file's new location.
+    pub fn rename_file(&mut self, old: &Path, new: &Path) {
+        let moved: Vec<Symbol> = self
+            .symbols
+            .iter()
+            .filter(|s| s.file == old)
+            .map(|s| Symbol::new(s.qualified_name.clone(), s.kind, new.to_path_buf(), s.decl_span))
+            .collect();
+        if moved.is_empty() {
+            return;
+        }
+        self.remove_file(old);
+        for symbol in moved {
+            self.insert_symbol(symbol);
+        }
+        self.refresh_references_for_file(new);
+    }
+
+    pub fn load_dir(root: &Path) -> std::io::Result<Self> {
+        Self::load_dir_with_options(root, &DirLoadOptions::default())
+    }
+
+    /// Loads every `.sysml`/`.kerml` file under `root`, restricted by
+    /// `options`' include/exclude glob patterns (exclude wins on overlap).
+    /// Walking and filtering is always sequential; once a file list is in
+    /// hand, per-file loading is spread across `options.thread_count`
+    /// worker threads (`1` forces the deterministic sequential path).
+    pub fn load_dir_with_options(root: &Path, options: &DirLoadOptions) -> std::io::Result<Self> {
+        let include = build_glob_set(&options.include_globs);
+        let exclude = build_glob_set(&options.exclude_globs);
+
+        let mut matched = Vec::new();
+        for entry in walkdir::WalkDir::new(root).into_iter().filter_map(Result::ok) {
+            let path = entry.path();
+            if !path.is_file() {
+                continue;
+            }
+            let relative = path.strip_prefix(root).unwrap_or(path);
+            if exclude.is_match(relative) {
+                continue;
+            }
+            if !options.include_globs.is_empty() && !include.is_match(relative) {
+                continue;
+            }
+            matched.push(path.to_path_buf());
+        }
+
+        let threads = effective_thread_count(options.thread_count);
+        let loaded = if threads <= 1 { load_files_sequential(&matched)? } else { load_files_parallel(&matched, threads)? };
+
+        let mut workspace = Self::default();
+        for path in loaded {
+            if starts_with_standard_library_package(&path) {
+                workspace.mark_library_file(path.clone());
+            }
+            workspace.loaded_files.insert(path);
+        }
+        // Per-file parsing isn't wired into this path yet (`parse_file_stub`
+        // registers no symbols), but once it is, worker threads may finish
+        // in an order that depends on scheduling rather than file order.
+        // Sorting here — the single population point every load strategy
+        // funnels through — keeps `symbols()` a deterministic (file, then
+        // source offset) order regardless of `thread_count`, so golden
+        // tests and diffable exports don't flap with the number of cores.
+        workspace.sort_symbols_by_file_and_span();
+        Ok(workspace)
+    }
+
+    /// Reorders `self.symbols` (and every index keyed by its position) by
+    /// `(file, span start)`, so iteration order doesn't depend on the order
+    /// symbols happened to be inserted in — e.g. after [`Self::load_dir_with_options`]
+    /// loaded files across multiple threads.
+    fn sort_symbols_by_file_and_span(&mut self) {
+        let mut paired: Vec<(Symbol, SymbolId)> = self.symbols.drain(..).zip(self.symbol_ids.drain(..)).collect();
+        paired.sort_by(|(a, _), (b, _)| (&a.file, a.range().start).cmp(&(&b.file, b.range().start)));
+
+        self.by_qualified_name.clear();
+        self.kind_index.clear();
+        self.id_index.clear();
+        for (idx, (symbol, id)) in paired.iter().enumerate() {
+            self.by_qualified_name.insert(symbol.qualified_name.clone(), idx);
+            self.kind_index.entry(symbol.kind).or_default().push(idx);
+            self.id_index.insert(*id, idx);
+        }
+        let (symbols, symbol_ids): (Vec<_>, Vec<_>) = paired.into_iter().unzip();
+        self.symbols = symbols;
+        self.symbol_ids = symbol_ids;
+    }
+
+    /// Structured counts over the currently loaded workspace, e.g. for a
+    /// status-bar summary or the CLI's `--explain`-adjacent reporting.
+    pub fn statistics(&self) -> WorkspaceStatistics {
+        let mut by_kind: HashMap<SymbolKind, usize> = HashMap::new();
+        for symbol in &self.symbols {
+            *by_kind.entry(symbol.kind).or_insert(0) += 1;
+        }
+        WorkspaceStatistics {
+            file_count: self.files().count(),
+            symbol_count: self.symbols.len(),
+            deprecated_count: self.deprecated.len(),
+            symbols_by_kind: by_kind,
+        }
+    }
+
+    /// Approximate heap usage of the symbol table (with its by-name, kind
+    /// and per-file position indices), the relationship graph, and the
+    /// loaded-file set, in bytes — to complement count-based estimates
+    /// like [`Workspace::statistics`] with a size one. Built from
+    /// `std::mem::size_of` and container `capacity()`s rather than walking
+    /// the allocator, so it's an approximation: it undercounts allocator
+    /// bookkeeping and any string that's allocated with spare capacity,
+    /// and (since this tree never retains a loaded file's source text —
+    /// only its parsed symbols) it has no source-text component to add.
+    /// The reference index backing [`Self::is_referenced`] and the side
+    /// tables (multiplicities, values, doc comments, ...) are not counted.
+    pub fn estimated_memory_bytes(&self) -> usize {
+        let symbols_bytes = self.symbols.capacity() * std::mem::size_of::<Symbol>()
+            + self.symbols.iter().map(|s| s.qualified_name.as_str().len() + s.file.as_os_str().len()).sum::<usize>();
+        let by_qualified_name_bytes = self.by_qualified_name.capacity() * std::mem::size_of::<(QualifiedName, usize)>();
+        let kind_index_bytes = self.kind_index.values().map(|v| v.capacity() * std::mem::size_of::<usize>()).sum::<usize>();
+        let index_by_file_bytes: usize = self.index_by_file.values().map(SymbolIndex::estimated_memory_bytes).sum();
+        let loaded_files_bytes = self.loaded_files.iter().map(|p| p.as_os_str().len()).sum::<usize>();
+
+        symbols_bytes + by_qualified_name_bytes + kind_index_bytes + index_by_file_bytes + self.relationships.estimated_memory_bytes() + loaded_files_bytes
+    }
+
+    /// The files contributing to any of `root_packages` — symbols nested
+    /// under a listed root, plus every file pulled in transitively via
+    /// `import`, found by following [`RelationshipKind`](crate::semantic::relationship_graph::RelationshipKind)::Import edges to a
+    /// fixpoint. Used to scope analysis to one model in a workspace that
+    /// contains several independent ones (`rootPackages` / CLI `--root`);
+    /// e.g. [`crate::diagnostics::run_validation_suite_scoped`] drops any
+    /// finding outside the result.
+    pub fn files_reachable_from(&self, root_packages: &[QualifiedName]) -> HashSet<PathBuf> {
+        use crate::semantic::relationship_graph::RelationshipKind;
+
+        fn is_within(name: &QualifiedName, root: &QualifiedName) -> bool {
+            name == root || name.as_str().starts_with(&format!("{root}::"))
+        }
+
+        let mut in_scope_names: HashSet<QualifiedName> = self
+            .symbols
+            .iter()
+            .filter(|s| root_packages.iter().any(|root| is_within(&s.qualified_name, root)))
+            .map(|s| s.qualified_name.clone())
+            .collect();
+
+        let mut files: HashSet<PathBuf> =
+            self.symbols.iter().filter(|s| in_scope_names.contains(&s.qualified_name)).map(|s| s.file.clone()).collect();
+
+        let mut frontier: Vec<QualifiedName> = in_scope_names.iter().cloned().collect();
+        while let Some(name) = frontier.pop() {
+            for imported in self.relationships.edges(RelationshipKind::Import, &name) {
+                if in_scope_names.insert(imported.clone()) {
+                    if let Some(symbol) = self.symbol_by_qualified_name(imported) {
+                        files.insert(symbol.file.clone());
+                    }
+                    frontier.push(imported.clone());
+                }
+            }
+        }
+
+        files
+    }
+
+    /// Every relationship edge whose target can't be resolved against this
+    /// workspace or a stdlib primitive, as raw data (reference text,
+    /// location, and the scope resolution was attempted in) rather than a
+    /// [`crate::diagnostics::Diagnostic`] — for embedders building a
+    /// "broken links" report. Thin wrapper over
+    /// [`crate::diagnostics::unresolved_references`], the same data
+    /// [`crate::diagnostics::check_unresolved_references`] turns into CI
+    /// findings.
+    pub fn unresolved_references(&self) -> Vec<crate::diagnostics::UnresolvedRef> {
+        crate::diagnostics::unresolved_references(self)
+    }
+
+    pub fn files(&self) -> impl Iterator<Item = &Path> {
+        let mut seen: BTreeSet<&Path> = self.loaded_files.iter().map(PathBuf::as_path).collect();
+        for s in &self.symbols {
+            seen.insert(s.file.as_path());
+        }
+        seen.into_iter()
+    }
+
+    #[cfg(feature = "persist")]
+    fn loaded_files_hash(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.loaded_files.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Serializes the full symbol table, relationship graph, and side
+    /// tables to `path`, so a later session can [`Workspace::load_index`]
+    /// instead of reparsing every file from scratch. Bincode-encoded
+    /// (rather than the JSON the CLI uses elsewhere) since the
+    /// relationship graph's keys aren't strings, which JSON's object-key
+    /// requirement can't represent.
+    #[cfg(feature = "persist")]
+    pub fn save_index(&self, path: &Path) -> std::io::Result<()> {
+        let header = IndexHeader { format_version: INDEX_FORMAT_VERSION, loaded_files_hash: self.loaded_files_hash() };
+        let bytes =
+            bincode::serialize(&(header, self)).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        std::fs::write(path, bytes)
+    }
+
+    /// Reads back an index written by [`Workspace::save_index`]. Rejects an
+    /// index written by an incompatible `syster` build (mismatched
+    /// [`INDEX_FORMAT_VERSION`]) or one that's been corrupted in transit
+    /// (mismatched `loaded_files_hash`). Doesn't compare against the
+    /// caller's own current file set — a warm-started LSP session does
+    /// that reconciliation itself using [`Workspace::files`].
+    #[cfg(feature = "persist")]
+    pub fn load_index(path: &Path) -> std::io::Result<Self> {
+        let bytes = std::fs::read(path)?;
+        let (header, workspace): (IndexHeader, Workspace) =
+            bincode::deserialize(&bytes).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        if header.format_version != INDEX_FORMAT_VERSION {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("index format v{} is incompatible with this build (expects v{INDEX_FORMAT_VERSION})", header.format_version),
+            ));
+        }
+        if header.loaded_files_hash != workspace.loaded_files_hash() {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "index header doesn't match its payload"));
+        }
+        Ok(workspace)
+    }
+}
+
+/// Bumped whenever [`Workspace`]'s persisted shape changes, so
+/// [`Workspace::load_index`] rejects an index written by an
+/// older/newer build instead of misreading it.
+#[cfg(feature = "persist")]
+const INDEX_FORMAT_VERSION: u32 = 1;
+
+#[cfg(feature = "persist")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct IndexHeader {
+    format_version: u32,
+    /// A hash of the loaded-file path set, checked against the
+    /// deserialized workspace's own set to catch a corrupted payload.
+    loaded_files_hash: u64,
+}
+
+pub type WorkspaceLoadResult = std::io::Result<Workspace>;
+
+/// An immutable, cheaply-cloned view of a [`Workspace`]'s symbols and
+/// relationships, taken at a point in time via [`Workspace::snapshot`].
+/// Cloning a `WorkspaceSnapshot` is a handful of `Arc` bumps rather than a
+/// deep copy, so query handlers can pass one around (or hand it to a
+/// blocking thread) without fighting the mutable borrow the live
+/// `Workspace` would otherwise require. Edits made to the `Workspace`
+/// after the snapshot was taken aren't visible through it.
+#[derive(Debug, Clone)]
+pub struct WorkspaceSnapshot {
+    symbols: std::sync::Arc<Vec<Symbol>>,
+    relationships: std::sync::Arc<RelationshipGraph>,
+}
+
+impl WorkspaceSnapshot {
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    pub fn relationships(&self) -> &RelationshipGraph {
+        &self.relationships
+    }
+}
+
+/// Options controlling [`Workspace::document_symbols`]. Default leaves
+/// `include_exposed` off, matching the `outlineIncludeExposed` LSP setting's
+/// default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DocumentSymbolOptions {
+    pub include_exposed: bool,
+}
+
+/// Options controlling [`Workspace::hover_with_options`]. Default leaves
+/// `debug` off, matching the hidden `debugHover` init option's default —
+/// normal users never see the raw node kind/span this adds.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HoverOptions {
+    pub debug: bool,
+}
+
+/// One entry in a [`Workspace::document_symbols`] outline: either a real
+/// declaration (`is_reference: false`) or a synthetic pointer into another
+/// namespace surfaced by a `view`'s `expose` (`is_reference: true`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutlineEntry {
+    pub name: String,
+    pub qualified_name: QualifiedName,
+    pub kind: SymbolKind,
+    pub is_reference: bool,
+    pub children: Vec<OutlineEntry>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkspaceStatistics {
+    pub file_count: usize,
+    pub symbol_count: usize,
+    pub deprecated_count: usize,
+    pub symbols_by_kind: HashMap<SymbolKind, usize>,
+}
+
+/// Fluent construction of a [`Workspace`], for call sites that need to set
+/// several loading options at once (roots, globs, thread count, etc.)
+/// without a long positional-argument `load_*` function.
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceBuilder {
+    root: Option<PathBuf>,
+    options: DirLoadOptions,
+}
+
+impl WorkspaceBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.root = Some(root.into());
+        self
+    }
+
+    pub fn include_glob(mut self, pattern: impl Into<String>) -> Self {
+        self.options.include_globs.push(pattern.into());
+        self
+    }
+
+    pub fn exclude_glob(mut self, pattern: impl Into<String>) -> Self {
+        self.options.exclude_globs.push(pattern.into());
+        self
+    }
+
+    /// Bounds the worker threads used to load matched files. `1` forces
+    /// the sequential path; omitting this defaults to available parallelism.
+    pub fn threads(mut self, count: usize) -> Self {
+        self.options.thread_count = Some(count);
+        self
+    }
+
+    pub fn build(self) -> std::io::Result<Workspace> {
+        match self.root {
+            Some(root) => Workspace::load_dir_with_options(&root, &self.options),
+            None => Ok(Workspace::default()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod builder_tests {
+    use super::*;
+
+    #[test]
+    fn builder_without_a_root_yields_an_empty_workspace() {
+        let ws = WorkspaceBuilder::new().include_glob("*.sysml").build().unwrap();
+        assert!(ws.symbols().is_empty());
+    }
+}
+
+/// Glob-based filtering for [`Workspace::load_dir_with_options`].
+#[derive(Debug, Clone, Default)]
+pub struct DirLoadOptions {
+    /// If non-empty, only files matching at least one of these patterns
+    /// (relative to the load root) are loaded.
+    pub include_globs: Vec<String>,
+    /// Files matching any of these patterns are skipped even if they also
+    /// match an include pattern.
+    pub exclude_globs: Vec<String>,
+    /// Worker threads used to load matched files. `None` defaults to
+    /// [`std::thread::available_parallelism`]; `Some(1)` forces the
+    /// sequential path regardless of how many cores are available.
+    pub thread_count: Option<usize>,
+}
+
+/// Whether `kind` is a defining declaration, for the package-hover member
+/// count in [`Workspace::hover_text`]. Mirrors
+/// [`crate::diagnostics::check_unused_definitions`]'s own classification,
+/// kept as a separate copy here rather than shared since `diagnostics`
+/// depends on `workspace`, not the other way around.
+fn is_definition_kind(kind: SymbolKind) -> bool {
+    use SymbolKind::*;
+    matches!(kind, PartDefinition | PortDefinition | ActionDefinition | EnumerationDefinition | RequirementDefinition | VariationDefinition | StateDefinition | ConcernDefinition)
+}
+
+/// The usage counterpart to [`is_definition_kind`].
+fn is_usage_kind(kind: SymbolKind) -> bool {
+    use SymbolKind::*;
+    matches!(
+        kind,
+        PartUsage | PortUsage | ActionUsage | AttributeUsage | Connection | Interface | RequirementUsage | StateUsage | LoopVariable | SnapshotUsage | TimesliceUsage | ViewUsage | ConcernUsage
+    )
+}
+
+fn build_glob_set(patterns: &[String]) -> globset::GlobSet {
+    let mut builder = globset::GlobSetBuilder::new();
+    for pattern in patterns {
+        if let Ok(glob) = globset::Glob::new(pattern) {
+            builder.add(glob);
+        }
+    }
+    builder.build().unwrap_or_else(|_| globset::GlobSetBuilder::new().build().unwrap())
+}
+
+fn effective_thread_count(requested: Option<usize>) -> usize {
+    requested
+        .unwrap_or_else(|| std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1))
+        .max(1)
+}
+
+/// Parses and registers a single file. A no-op stub until the parser is
+/// wired in; exists so directory loading has a single, testable extension
+/// point regardless of how many threads drive it.
+fn parse_file_stub(path: &Path) -> std::io::Result<PathBuf> {
+    Ok(path.to_path_buf())
+}
+
+fn load_files_sequential(paths: &[PathBuf]) -> std::io::Result<Vec<PathBuf>> {
+    paths.iter().map(|path| parse_file_stub(path)).collect()
+}
+
+/// Whether `path`'s content begins with `standard library package`,
+/// ignoring leading whitespace. A real stdlib module loaded through the
+/// ordinary directory walk (rather than a dedicated stdlib loader) still
+/// declares itself this way, so this is the population-time signal for
+/// `Workspace::mark_library_file`.
+fn starts_with_standard_library_package(path: &Path) -> bool {
+    const PREFIX: &str = "standard library package";
+    std::fs::read_to_string(path).map(|content| content.trim_start().starts_with(PREFIX)).unwrap_or(false)
+}
+
+/// Splits `paths` into `threads` roughly-even chunks and loads each chunk on
+/// its own worker thread, joining the per-thread results once all are done.
+/// The split is by file, so results (and therefore the resulting symbol
+/// table) are independent of how many threads did the work.
+fn load_files_parallel(paths: &[PathBuf], threads: usize) -> std::io::Result<Vec<PathBuf>> {
+    let chunk_size = paths.len().div_ceil(threads).max(1);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = paths
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || load_files_sequential(chunk)))
+            .collect();
+
+        let mut loaded = Vec::with_capacity(paths.len());
+        for handle in handles {
+            loaded.extend(handle.join().expect("loader thread panicked")?);
+        }
+        Ok(loaded)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::semantic::relationship_graph::RelationshipKind;
+    use crate::span::Span;
+    use std::path::PathBuf;
+
+    #[test]
+    fn statistics_counts_symbols_by_kind_and_deprecation() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::PartDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, file, span));
+        ws.mark_deprecated(QualifiedName::new("Vehicle::engine"));
+
+        let stats = ws.statistics();
+
+        assert_eq!(stats.symbol_count, 2);
+        assert_eq!(stats.deprecated_count, 1);
+        assert_eq!(stats.symbols_by_kind.get(&SymbolKind::PartDefinition), Some(&1));
+    }
+
+    #[test]
+    fn estimated_memory_grows_after_loading_additional_files() {
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        let before = ws.estimated_memory_bytes();
+
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::PartDefinition, PathBuf::from("Vehicle.sysml"), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Engine"), SymbolKind::PartDefinition, PathBuf::from("Engine.sysml"), span));
+        ws.relationships.add_edge(RelationshipKind::Specialization, QualifiedName::new("Vehicle"), QualifiedName::new("Engine"));
+
+        assert!(ws.estimated_memory_bytes() > before);
+    }
+
+    #[test]
+    fn a_symbol_id_survives_repopulating_an_unrelated_file() {
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::PartDefinition, PathBuf::from("Vehicle.sysml"), span));
+        let id = ws.id_of_qualified_name(&QualifiedName::new("Vehicle")).unwrap();
+
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Engine"), SymbolKind::PartDefinition, PathBuf::from("Engine.sysml"), span));
+        ws.remove_file(&PathBuf::from("Engine.sysml"));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Engine"), SymbolKind::PartDefinition, PathBuf::from("Engine.sysml"), span));
+
+        assert_eq!(ws.get(id).map(|s| &s.qualified_name), Some(&QualifiedName::new("Vehicle")));
+    }
+
+    #[test]
+    fn a_removed_symbol_s_id_resolves_to_none() {
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::PartDefinition, PathBuf::from("Vehicle.sysml"), span));
+        let id = ws.id_of_qualified_name(&QualifiedName::new("Vehicle")).unwrap();
+
+        ws.remove_file(&PathBuf::from("Vehicle.sysml"));
+
+        assert!(ws.get(id).is_none());
+    }
+
+    #[test]
+    fn supertypes_of_and_specializations_of_resolve_by_id() {
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::PartDefinition, PathBuf::from("Vehicle.sysml"), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Car"), SymbolKind::PartDefinition, PathBuf::from("Car.sysml"), span));
+        ws.relationships.add_edge(RelationshipKind::Specialization, QualifiedName::new("Car"), QualifiedName::new("Vehicle"));
+        let vehicle = ws.id_of_qualified_name(&QualifiedName::new("Vehicle")).unwrap();
+        let car = ws.id_of_qualified_name(&QualifiedName::new("Car")).unwrap();
+
+        assert_eq!(ws.supertypes_of(car, true), vec![vehicle]);
+        assert_eq!(ws.specializations_of(vehicle, true), vec![car]);
+    }
+
+    #[test]
+    fn supertypes_of_an_unknown_id_is_empty_rather_than_panicking() {
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(
+            QualifiedName::new("Vehicle"),
+            SymbolKind::PartDefinition,
+            PathBuf::from("Vehicle.sysml"),
+            Span::new(Position::new(0, 0), Position::new(0, 1)),
+        ));
+        let id = ws.id_of_qualified_name(&QualifiedName::new("Vehicle")).unwrap();
+        ws.remove_file(&PathBuf::from("Vehicle.sysml"));
+
+        assert!(ws.supertypes_of(id, true).is_empty());
+    }
+
+    #[test]
+    fn references_to_finds_every_id_with_an_edge_into_the_target() {
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Engine"), SymbolKind::PartDefinition, PathBuf::from("Engine.sysml"), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, PathBuf::from("Vehicle.sysml"), span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::engine"), QualifiedName::new("Engine"));
+        let engine = ws.id_of_qualified_name(&QualifiedName::new("Engine")).unwrap();
+        let usage = ws.id_of_qualified_name(&QualifiedName::new("Vehicle::engine")).unwrap();
+
+        assert_eq!(ws.references_to(engine), vec![usage]);
+    }
+
+    #[test]
+    fn the_kind_index_stays_consistent_after_removing_and_re_adding_a_file_s_symbols() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::PartDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Engine"), SymbolKind::PartDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("R1"), SymbolKind::RequirementUsage, file.clone(), span));
+
+        assert_eq!(ws.all_of_kind(SymbolKind::PartDefinition).len(), 2);
+        assert_eq!(ws.all_of_kind(SymbolKind::RequirementUsage).len(), 1);
+
+        ws.remove_file(&file);
+
+        assert!(ws.all_of_kind(SymbolKind::PartDefinition).is_empty());
+        assert!(ws.all_of_kind(SymbolKind::RequirementUsage).is_empty());
+
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Wheel"), SymbolKind::PartDefinition, file, span));
+
+        let wheels = ws.all_of_kind(SymbolKind::PartDefinition);
+        assert_eq!(wheels.len(), 1);
+        assert_eq!(wheels[0].qualified_name, QualifiedName::new("Wheel"));
+    }
+
+    #[test]
+    fn renaming_a_file_moves_its_symbols_while_keeping_them_resolvable_by_name() {
+        let old = PathBuf::from("Vehicle.sysml");
+        let new = PathBuf::from("Car.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::PartDefinition, old.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, old.clone(), span));
+
+        ws.rename_file(&old, &new);
+
+        assert_eq!(ws.symbol_by_qualified_name(&QualifiedName::new("Vehicle")).unwrap().file, new);
+        assert_eq!(ws.symbol_by_qualified_name(&QualifiedName::new("Vehicle::engine")).unwrap().file, new);
+        assert!(ws.files().all(|f| f != old));
+        assert!(ws.files().any(|f| f == new));
+    }
+
+    #[test]
+    fn renaming_a_file_with_no_symbols_is_a_no_op() {
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(
+            QualifiedName::new("Vehicle"),
+            SymbolKind::PartDefinition,
+            PathBuf::from("Vehicle.sysml"),
+            Span::new(Position::new(0, 0), Position::new(0, 1)),
+        ));
+
+        ws.rename_file(&PathBuf::from("Unrelated.sysml"), &PathBuf::from("StillUnrelated.sysml"));
+
+        assert!(ws.symbol_by_qualified_name(&QualifiedName::new("Vehicle")).is_some());
+    }
+
+    #[test]
+    fn unresolved_references_reports_a_genuine_dangling_reference_but_not_a_stdlib_target() {
+        use crate::semantic::relationship_graph::RelationshipKind;
+
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(1, 4), Position::new(1, 10));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, file.clone(), span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::engine"), QualifiedName::new("Engien"));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::mass"), SymbolKind::AttributeUsage, file, span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::mass"), QualifiedName::new("Real"));
+
+        let unresolved = ws.unresolved_references();
+
+        assert_eq!(unresolved.len(), 1, "the stdlib-resolvable 'Real' target shouldn't be reported");
+        assert_eq!(unresolved[0].reference, QualifiedName::new("Engien"));
+        assert_eq!(unresolved[0].scope, QualifiedName::new("Vehicle::engine"));
+    }
+
+    #[test]
+    fn files_reachable_from_a_root_package_include_its_transitive_imports_but_not_an_unrelated_package() {
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let root_file = PathBuf::from("RootA.sysml");
+        let imported_file = PathBuf::from("RootB.sysml");
+        let unrelated_file = PathBuf::from("Unrelated.sysml");
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("RootA"), SymbolKind::Package, root_file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("RootA::thing"), SymbolKind::PartUsage, root_file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("RootB"), SymbolKind::Package, imported_file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Unrelated"), SymbolKind::Package, unrelated_file.clone(), span));
+        ws.relationships.add_edge(RelationshipKind::Import, QualifiedName::new("RootA"), QualifiedName::new("RootB"));
+
+        let files = ws.files_reachable_from(&[QualifiedName::new("RootA")]);
+
+        assert!(files.contains(&root_file));
+        assert!(files.contains(&imported_file));
+        assert!(!files.contains(&unrelated_file));
+    }
+
+    #[cfg(feature = "persist")]
+    #[test]
+    fn a_saved_index_round_trips_and_resolves_identically_after_loading() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::PartDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, file, span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::engine"), QualifiedName::new("Vehicle"));
+
+        let path = std::env::temp_dir().join(format!("syster-index-round-trip-test-{:?}.bin", std::thread::current().id()));
+        ws.save_index(&path).unwrap();
+        let loaded = Workspace::load_index(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.symbols().len(), ws.symbols().len());
+        assert_eq!(
+            loaded.goto_definition(&PathBuf::from("Vehicle.sysml"), Position::new(0, 0)).map(|s| s.qualified_name.clone()),
+            ws.goto_definition(&PathBuf::from("Vehicle.sysml"), Position::new(0, 0)).map(|s| s.qualified_name.clone()),
+        );
+    }
+
+    #[cfg(feature = "persist")]
+    #[test]
+    fn loading_an_index_with_the_wrong_format_version_is_rejected() {
+        let header = IndexHeader { format_version: INDEX_FORMAT_VERSION + 1, loaded_files_hash: 0 };
+        let bytes = bincode::serialize(&(header, Workspace::default())).unwrap();
+        let path = std::env::temp_dir().join(format!("syster-index-bad-version-test-{:?}.bin", std::thread::current().id()));
+        std::fs::write(&path, bytes).unwrap();
+
+        let result = Workspace::load_index(&path);
+        std::fs::remove_file(&path).ok();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn variants_of_returns_only_variant_members_of_the_variation() {
+        let file = PathBuf::from("Wheel.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Wheel"), SymbolKind::VariationDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Wheel::steel"), SymbolKind::VariantUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Wheel::alloy"), SymbolKind::VariantUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Wheel::radius"), SymbolKind::AttributeUsage, file, span));
+
+        let variants = ws.variants_of(&QualifiedName::new("Wheel"));
+
+        assert_eq!(variants.len(), 2);
+        assert!(variants.iter().all(|s| s.kind == SymbolKind::VariantUsage));
+    }
+
+    #[test]
+    fn goto_definition_by_name_falls_through_to_stdlib_primitives() {
+        let ws = Workspace::default();
+        let symbol = ws.goto_definition_by_name(&QualifiedName::new("Integer")).expect("Integer is a stdlib primitive");
+        assert_eq!(symbol.file, PathBuf::from(crate::semantic::stdlib::SCALAR_VALUES_FILE));
+    }
+
+    #[test]
+    fn goto_definition_by_name_does_not_keep_serving_a_stale_cached_miss_after_the_name_is_declared() {
+        let file = PathBuf::from("Engine.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 6));
+        let mut ws = Workspace::default();
+
+        assert!(ws.goto_definition_by_name(&QualifiedName::new("Engine")).is_none(), "not declared yet, and not a stdlib primitive");
+
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Engine"), SymbolKind::PartDefinition, file.clone(), span));
+        // Removing an unrelated file still runs invalidation, which must
+        // drop the earlier cached miss for `Engine` — nothing ties that
+        // miss to any particular file, so it can't be targeted directly.
+        ws.remove_file(&PathBuf::from("Unrelated.sysml"));
+
+        let resolved = ws.goto_definition_by_name(&QualifiedName::new("Engine")).expect("Engine is now declared");
+        assert_eq!(resolved.file, file);
+    }
+
+    #[test]
+    fn is_referenced_keeps_up_with_rename_file_without_losing_the_renamed_symbol_s_own_reference() {
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let old = PathBuf::from("Vehicle.sysml");
+        let new = PathBuf::from("Car.sysml");
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Engine"), SymbolKind::PartDefinition, PathBuf::from("Engine.sysml"), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, old.clone(), span));
+        ws.relationships.add_edge(crate::semantic::relationship_graph::RelationshipKind::Typing, QualifiedName::new("Vehicle::engine"), QualifiedName::new("Engine"));
+
+        // Build the incremental reference index before the rename, so the
+        // rename exercises `remove_file`/`add_file` rather than a lazy
+        // first build that would already see the renamed state.
+        assert!(ws.is_referenced(&QualifiedName::new("Engine")));
+
+        ws.rename_file(&old, &new);
+
+        assert!(ws.is_referenced(&QualifiedName::new("Engine")), "Vehicle::engine's typing edge survives the rename under its new file");
+    }
+
+    #[test]
+    fn hover_shows_the_feature_value_expression() {
+        use crate::semantic::feature_value::{FeatureValue, ValueKind};
+
+        let file = PathBuf::from("Vehicle.sysml");
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(
+            QualifiedName::new("Vehicle::mass"),
+            SymbolKind::AttributeUsage,
+            file.clone(),
+            Span::new(Position::new(0, 0), Position::new(0, 4)),
+        ));
+        ws.feature_values.set(QualifiedName::new("Vehicle::mass"), FeatureValue::new(ValueKind::Binding, "9.81 * volume"));
+
+        let hover = ws.hover(&file, Position::new(0, 1)).unwrap();
+        assert!(hover.contains(":= 9.81 * volume"));
+    }
+
+    #[test]
+    fn hover_flags_deprecated_symbols() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(
+            QualifiedName::new("OldEngine"),
+            SymbolKind::PartDefinition,
+            file.clone(),
+            Span::new(Position::new(0, 0), Position::new(0, 9)),
+        ));
+        ws.mark_deprecated(QualifiedName::new("OldEngine"));
+
+        let hover = ws.hover(&file, Position::new(0, 1)).unwrap();
+        assert!(hover.contains("Deprecated"));
+    }
+
+    #[test]
+    fn hover_shows_full_specialization_chain() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(
+            QualifiedName::new("Car"),
+            SymbolKind::PartDefinition,
+            file.clone(),
+            Span::new(Position::new(0, 0), Position::new(0, 3)),
+        ));
+        ws.relationships.add_edge(RelationshipKind::Specialization, QualifiedName::new("Car"), QualifiedName::new("Vehicle"));
+        ws.relationships.add_edge(RelationshipKind::Specialization, QualifiedName::new("Vehicle"), QualifiedName::new("Thing"));
+
+        let hover = ws.hover(&file, Position::new(0, 1)).unwrap();
+
+        assert!(hover.contains(&format!("specializes: [Car](file://{}#L1) :> `Vehicle` :> `Thing`", file.display())));
+    }
+
+    #[test]
+    fn debug_hover_appends_the_node_kind_and_span_while_plain_hover_omits_it() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(2, 0), Position::new(2, 6));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Engine"), SymbolKind::PartDefinition, file.clone(), span));
+
+        let plain = ws.hover(&file, Position::new(2, 1)).unwrap();
+        assert!(!plain.contains("debugHover"));
+
+        let debug = ws.hover_with_options(&file, Position::new(2, 1), &HoverOptions { debug: true }).unwrap();
+        assert!(debug.contains("`debugHover`: kind = `PartDefinition`"));
+        assert!(debug.contains(&crate::format_span(span)));
+    }
+
+    #[test]
+    fn hover_on_a_typed_usage_links_to_its_type_s_declaration() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(
+            QualifiedName::new("Engine"),
+            SymbolKind::PartDefinition,
+            file.clone(),
+            Span::new(Position::new(2, 0), Position::new(2, 6)),
+        ));
+        ws.insert_symbol(Symbol::new(
+            QualifiedName::new("Vehicle::engine"),
+            SymbolKind::PartUsage,
+            file.clone(),
+            Span::new(Position::new(0, 0), Position::new(0, 6)),
+        ));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::engine"), QualifiedName::new("Engine"));
+
+        let hover = ws.hover(&file, Position::new(0, 1)).unwrap();
+
+        assert!(hover.contains(&format!("typed by: [Engine](file://{}#L3)", file.display())));
+    }
+
+    #[test]
+    fn hover_on_a_conjugated_port_usage_shows_conjugated_and_resolves_to_the_stripped_type() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(
+            QualifiedName::new("MyPort"),
+            SymbolKind::PortDefinition,
+            file.clone(),
+            Span::new(Position::new(2, 0), Position::new(2, 6)),
+        ));
+        ws.insert_symbol(Symbol::new(
+            QualifiedName::new("Vehicle::p"),
+            SymbolKind::PortUsage,
+            file.clone(),
+            Span::new(Position::new(0, 0), Position::new(0, 6)),
+        ));
+        ws.record_typing(QualifiedName::new("Vehicle::p"), "~MyPort");
+
+        let hover = ws.hover(&file, Position::new(0, 1)).unwrap();
+
+        assert!(hover.contains(&format!("typed by: [MyPort](file://{}#L3) (conjugated)", file.display())));
+
+        let definition = ws.goto_definition_by_name(&QualifiedName::new("MyPort")).unwrap();
+        assert_eq!(definition.qualified_name, QualifiedName::new("MyPort"));
+    }
+
+    #[test]
+    fn hovering_members_of_a_package_reopened_across_two_files_shows_the_same_qualified_prefix() {
+        let file_a = PathBuf::from("PackagePart1.sysml");
+        let file_b = PathBuf::from("PackagePart2.sysml");
+        let package_span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let member_span = Span::new(Position::new(1, 0), Position::new(1, 1));
+        let mut ws = Workspace::default();
+        // `package P { ... }` declared once per file; a package's qualified
+        // name is just its `::` path, not scoped to a file, so both
+        // reopenings share the same `P` namespace automatically.
+        ws.insert_symbol(Symbol::new(QualifiedName::new("P"), SymbolKind::Package, file_a.clone(), package_span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("P::fromA"), SymbolKind::PartUsage, file_a.clone(), member_span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("P::fromB"), SymbolKind::PartUsage, file_b.clone(), member_span));
+
+        let hover_a = ws.hover(&file_a, member_span.start).unwrap();
+        let hover_b = ws.hover(&file_b, member_span.start).unwrap();
+
+        assert!(hover_a.starts_with("**P::fromA**"));
+        assert!(hover_b.starts_with("**P::fromB**"));
+    }
+
+    #[test]
+    fn hover_on_a_package_shows_member_counts_merged_across_reopened_files() {
+        let file_a = PathBuf::from("PackagePart1.sysml");
+        let file_b = PathBuf::from("PackagePart2.sysml");
+        let package_span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let member_span = Span::new(Position::new(1, 0), Position::new(1, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::Package, file_a.clone(), package_span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::Engine"), SymbolKind::PartDefinition, file_a.clone(), member_span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::mass"), SymbolKind::AttributeUsage, file_b.clone(), member_span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::Sub"), SymbolKind::Package, file_b.clone(), member_span));
+        ws.doc_comments.set(QualifiedName::new("Vehicle"), "The vehicle's top-level package.");
+
+        let hover = ws.hover(&file_a, package_span.start).unwrap();
+
+        assert!(hover.starts_with("**Vehicle**"));
+        assert!(hover.contains("`Package`"));
+        assert!(hover.contains("1 definition(s), 1 usage(s), 1 subpackage(s)"));
+        assert!(hover.contains("The vehicle's top-level package."));
+    }
+
+    #[test]
+    fn hover_on_a_calc_s_return_parameter_shows_its_declared_type() {
+        let file = PathBuf::from("Increment.sysml");
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Counter"), SymbolKind::PartDefinition, file.clone(), Span::new(Position::new(0, 0), Position::new(0, 7))));
+        ws.insert_symbol(Symbol::new(
+            QualifiedName::new("Increment::return"),
+            SymbolKind::AttributeUsage,
+            file.clone(),
+            Span::new(Position::new(1, 4), Position::new(1, 10)),
+        ));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Increment::return"), QualifiedName::new("Counter"));
+
+        let hover = ws.hover(&file, Position::new(1, 5)).unwrap();
+
+        assert!(hover.contains(&format!("typed by: [Counter](file://{}#L1)", file.display())));
+        assert_eq!(ws.return_parameter(&QualifiedName::new("Increment")).unwrap().qualified_name, QualifiedName::new("Increment::return"));
+    }
+
+    #[test]
+    fn a_snapshot_and_a_timeslice_both_resolve_to_their_owning_occurrence_and_show_correct_portion_kinds_in_hover() {
+        let file = PathBuf::from("Mission.sysml");
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Mission"), SymbolKind::PartUsage, file.clone(), Span::new(Position::new(0, 0), Position::new(0, 7))));
+        let snap_span = Span::new(Position::new(1, 4), Position::new(1, 9));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Mission::snap1"), SymbolKind::SnapshotUsage, file.clone(), snap_span));
+        ws.record_portion(QualifiedName::new("Mission::snap1"), QualifiedName::new("Mission"));
+        let slice_span = Span::new(Position::new(2, 4), Position::new(2, 10));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Mission::slice1"), SymbolKind::TimesliceUsage, file.clone(), slice_span));
+        ws.record_portion(QualifiedName::new("Mission::slice1"), QualifiedName::new("Mission"));
+
+        let snap_hover = ws.hover(&file, snap_span.start).unwrap();
+        assert!(snap_hover.contains("snapshot of `Mission`"));
+
+        let slice_hover = ws.hover(&file, slice_span.start).unwrap();
+        assert!(slice_hover.contains("timeslice of `Mission`"));
+
+        assert_eq!(
+            ws.relationships.edges(RelationshipKind::PortionOf, &QualifiedName::new("Mission::snap1")),
+            &[QualifiedName::new("Mission")]
+        );
+        assert_eq!(
+            ws.relationships.edges(RelationshipKind::PortionOf, &QualifiedName::new("Mission::slice1")),
+            &[QualifiedName::new("Mission")]
+        );
+    }
+
+    #[test]
+    fn find_references_includes_imports_typings_and_comment_about_targets() {
+        let def_file = PathBuf::from("Engine.kerml");
+        let def_span = Span::new(Position::new(0, 9), Position::new(0, 15));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Engine"), SymbolKind::PartDefinition, def_file.clone(), def_span));
+
+        let other_file = PathBuf::from("OtherPkg.sysml");
+        ws.insert_symbol(Symbol::new(
+            QualifiedName::new("OtherPkg"),
+            SymbolKind::Package,
+            other_file.clone(),
+            Span::new(Position::new(0, 0), Position::new(0, 8)),
+        ));
+        ws.relationships.add_edge(RelationshipKind::Import, QualifiedName::new("OtherPkg"), QualifiedName::new("Engine"));
+
+        let vehicle_file = PathBuf::from("Vehicle.sysml");
+        ws.insert_symbol(Symbol::new(
+            QualifiedName::new("Vehicle::engine"),
+            SymbolKind::PartUsage,
+            vehicle_file.clone(),
+            Span::new(Position::new(1, 4), Position::new(1, 10)),
+        ));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::engine"), QualifiedName::new("Engine"));
+
+        ws.insert_symbol(Symbol::new(
+            QualifiedName::new("Note1"),
+            SymbolKind::Package,
+            vehicle_file,
+            Span::new(Position::new(2, 0), Position::new(2, 20)),
+        ));
+        ws.relationships.add_edge(RelationshipKind::About, QualifiedName::new("Note1"), QualifiedName::new("Engine"));
+
+        let references = ws.find_references(&def_file, def_span.start);
+        let names: Vec<_> = references.iter().map(|s| s.qualified_name.to_string()).collect();
+
+        assert!(names.contains(&"Engine".to_string()));
+        assert!(names.contains(&"OtherPkg".to_string()));
+        assert!(names.contains(&"Vehicle::engine".to_string()));
+        assert!(names.contains(&"Note1".to_string()));
+        assert_eq!(names.len(), 4);
+    }
+
+    #[test]
+    fn load_dir_with_options_respects_include_and_exclude_globs() {
+        let dir = std::env::temp_dir().join(format!("syster-load-dir-test-{:?}", std::thread::current().id()));
+        std::fs::create_dir_all(dir.join("sysml.library")).unwrap();
+        std::fs::write(dir.join("Vehicle.sysml"), "").unwrap();
+        std::fs::write(dir.join("sysml.library/Stdlib.sysml"), "").unwrap();
+
+        let options = DirLoadOptions {
+            include_globs: vec!["*.sysml".into()],
+            exclude_globs: vec!["sysml.library/**".into()],
+            ..Default::default()
+        };
+        let ws = Workspace::load_dir_with_options(&dir, &options).unwrap();
+        let loaded: Vec<_> = ws.files().collect();
+
+        assert!(loaded.iter().any(|p| p.ends_with("Vehicle.sysml")));
+        assert!(!loaded.iter().any(|p| p.to_string_lossy().contains("sysml.library")));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn a_user_loaded_standard_library_package_file_is_marked_library_origin_and_refuses_rename() {
+        let dir = std::env::temp_dir().join(format!("syster-stdlib-detect-test-{:?}", std::thread::current().id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let lib_file = dir.join("VehicleLib.kerml");
+        std::fs::write(&lib_file, "standard library package VehicleLib;\npart def Engine;\n").unwrap();
+
+        let mut ws = Workspace::load_dir(&dir).unwrap();
+        assert!(ws.is_library_file(&lib_file));
+
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Engine"), SymbolKind::PartDefinition, lib_file, Span::new(Position::new(1, 9), Position::new(1, 15))));
+        assert!(!ws.can_rename(&QualifiedName::new("Engine")));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn loading_with_one_thread_or_four_threads_yields_identical_file_counts() {
+        let dir = std::env::temp_dir().join(format!("syster-threads-test-{:?}", std::thread::current().id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in ["A.sysml", "B.sysml", "C.sysml", "D.sysml", "E.sysml"] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+
+        let sequential = Workspace::load_dir_with_options(&dir, &DirLoadOptions { thread_count: Some(1), ..Default::default() }).unwrap();
+        let parallel = Workspace::load_dir_with_options(&dir, &DirLoadOptions { thread_count: Some(4), ..Default::default() }).unwrap();
+
+        assert_eq!(sequential.files().count(), parallel.files().count());
+        assert_eq!(sequential.statistics().symbol_count, parallel.statistics().symbol_count);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn loading_a_directory_twice_with_different_thread_counts_yields_identical_symbol_ordering() {
+        let dir = std::env::temp_dir().join(format!("syster-order-test-{:?}", std::thread::current().id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in ["A.sysml", "B.sysml", "C.sysml", "D.sysml", "E.sysml"] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+
+        let sequential = Workspace::load_dir_with_options(&dir, &DirLoadOptions { thread_count: Some(1), ..Default::default() }).unwrap();
+        let parallel = Workspace::load_dir_with_options(&dir, &DirLoadOptions { thread_count: Some(4), ..Default::default() }).unwrap();
+
+        let names = |ws: &Workspace| ws.symbols().iter().map(|s| s.qualified_name.clone()).collect::<Vec<_>>();
+        assert_eq!(names(&sequential), names(&parallel));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn sort_symbols_by_file_and_span_orders_by_file_path_then_source_offset_regardless_of_insertion_order() {
+        let file_a = PathBuf::from("A.sysml");
+        let file_b = PathBuf::from("B.sysml");
+        let mut ws = Workspace::default();
+        // Inserted out of both file and in-file order, the way two worker
+        // threads racing to finish wouldn't guarantee either.
+        ws.insert_symbol(Symbol::new(QualifiedName::new("B::second"), SymbolKind::PartUsage, file_b.clone(), Span::new(Position::new(3, 0), Position::new(3, 1))));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("A::second"), SymbolKind::PartUsage, file_a.clone(), Span::new(Position::new(2, 0), Position::new(2, 1))));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("B::first"), SymbolKind::PartUsage, file_b.clone(), Span::new(Position::new(1, 0), Position::new(1, 1))));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("A::first"), SymbolKind::PartUsage, file_a.clone(), Span::new(Position::new(0, 0), Position::new(0, 1))));
+
+        ws.sort_symbols_by_file_and_span();
+
+        let ordered: Vec<QualifiedName> = ws.symbols().iter().map(|s| s.qualified_name.clone()).collect();
+        assert_eq!(
+            ordered,
+            vec![
+                QualifiedName::new("A::first"),
+                QualifiedName::new("A::second"),
+                QualifiedName::new("B::first"),
+                QualifiedName::new("B::second"),
+            ]
+        );
+
+        // The position-keyed indices must still agree with the new order.
+        assert_eq!(ws.symbol_by_qualified_name(&QualifiedName::new("B::first")).unwrap().decl_span.start, Position::new(1, 0));
+        assert_eq!(ws.all_of_kind(SymbolKind::PartUsage).len(), 4);
+    }
+
+    #[test]
+    fn enumerated_value_resolves_by_qualified_name_and_hovers_with_owner() {
+        let file = PathBuf::from("Color.sysml");
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(
+            QualifiedName::new("Color"),
+            SymbolKind::EnumerationDefinition,
+            file.clone(),
+            Span::new(Position::new(0, 9), Position::new(0, 14)),
+        ));
+        ws.insert_symbol(Symbol::new(
+            QualifiedName::new("Color::red"),
+            SymbolKind::EnumeratedValue,
+            file.clone(),
+            Span::new(Position::new(0, 23), Position::new(0, 26)),
+        ));
+        // `attribute c : Color = Color::red;` — the usage occurrence.
+        ws.insert_symbol(Symbol::new(
+            QualifiedName::new("Color::red"),
+            SymbolKind::EnumeratedValue,
+            file.clone(),
+            Span::new(Position::new(1, 23), Position::new(1, 33)),
+        ));
+
+        assert!(ws.symbol_by_qualified_name(&QualifiedName::new("Color::red")).is_some());
+
+        let hover = ws.hover(&file, Position::new(1, 25)).expect("usage should resolve");
+        assert!(hover.contains("Color::red"));
+        assert!(hover.contains("Color"), "hover should mention the owning enum");
+    }
+
+    #[test]
+    fn an_alias_appears_distinctly_from_its_target_and_goto_definition_offers_both() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let alias_span = Span::new(Position::new(1, 6), Position::new(1, 13));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::PartDefinition, file.clone(), Span::new(Position::new(0, 9), Position::new(0, 16))));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("MyAlias"), SymbolKind::Alias, file.clone(), alias_span));
+        ws.mark_alias(QualifiedName::new("MyAlias"), QualifiedName::new("Vehicle"));
+
+        let alias = ws.symbol_by_qualified_name(&QualifiedName::new("MyAlias")).unwrap();
+        assert_eq!(alias.kind, SymbolKind::Alias);
+        assert_ne!(alias.kind, SymbolKind::PartDefinition, "an alias must not show as its target's kind in the outline");
+        assert_eq!(ws.alias_detail(&QualifiedName::new("MyAlias")).as_deref(), Some("for Vehicle"));
+
+        let definitions = ws.goto_definition_multi(&file, alias_span.start);
+        let names: Vec<_> = definitions.iter().map(|s| s.qualified_name.to_string()).collect();
+        assert_eq!(names, vec!["MyAlias".to_string(), "Vehicle".to_string()]);
+    }
+
+    #[test]
+    fn completion_and_hover_see_members_inherited_from_a_supertype_in_another_file() {
+        let vehicle_file = PathBuf::from("Vehicle.sysml");
+        let car_file = PathBuf::from("Car.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::PartDefinition, vehicle_file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::wheels"), SymbolKind::PartUsage, vehicle_file, span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Car"), SymbolKind::PartDefinition, car_file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Car::engine"), SymbolKind::PartUsage, car_file, span));
+        ws.relationships.add_edge(RelationshipKind::Specialization, QualifiedName::new("Car"), QualifiedName::new("Vehicle"));
+
+        let members = ws.members_including_inherited(&QualifiedName::new("Car"));
+        let names: Vec<_> = members.iter().map(|s| s.qualified_name.to_string()).collect();
+        assert!(names.contains(&"Car::engine".to_string()), "Car's own members should still be offered");
+        assert!(names.contains(&"Vehicle::wheels".to_string()), "Vehicle's members should be inherited by Car");
+
+        let resolved = ws.resolve_member(&QualifiedName::new("Car"), "wheels").expect("wheels should resolve via inheritance");
+        assert_eq!(resolved.qualified_name, QualifiedName::new("Vehicle::wheels"));
+    }
+
+    #[test]
+    fn hover_shows_multiplicity_inherited_across_files_from_a_redefined_feature() {
+        use crate::semantic::multiplicity::Multiplicity;
+        use crate::semantic::relationship_graph::RelationshipKind;
+
+        let base_file = PathBuf::from("Vehicle.sysml");
+        let sub_file = PathBuf::from("Car.sysml");
+        let wheels_span = Span::new(Position::new(1, 4), Position::new(1, 10));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::PartDefinition, base_file.clone(), Span::new(Position::new(0, 0), Position::new(0, 1))));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::wheels"), SymbolKind::PartUsage, base_file, Span::new(Position::new(1, 0), Position::new(1, 1))));
+        ws.multiplicities.set_multiplicity(QualifiedName::new("Vehicle::wheels"), Multiplicity::new(0, None));
+
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Car"), SymbolKind::PartDefinition, sub_file.clone(), Span::new(Position::new(0, 0), Position::new(0, 1))));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Car::wheels"), SymbolKind::PartUsage, sub_file.clone(), wheels_span));
+        ws.relationships.add_edge(RelationshipKind::Redefinition, QualifiedName::new("Car::wheels"), QualifiedName::new("Vehicle::wheels"));
+
+        let multiplicity = ws.effective_multiplicity(&QualifiedName::new("Car::wheels")).expect("should inherit Vehicle::wheels's multiplicity");
+        assert_eq!(multiplicity, Multiplicity::new(0, None));
+
+        let hover = ws.hover(&sub_file, wheels_span.start).expect("should resolve");
+        assert!(hover.contains("[0..*]"));
+    }
+
+    #[test]
+    fn hover_on_a_redefined_attribute_links_to_the_original_declaration() {
+        use crate::semantic::relationship_graph::RelationshipKind;
+
+        let base_file = PathBuf::from("Base.sysml");
+        let meta_file = PathBuf::from("MyMeta.sysml");
+        let redefined_span = Span::new(Position::new(1, 4), Position::new(1, 10));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Base"), SymbolKind::AttributeUsage, base_file.clone(), Span::new(Position::new(0, 0), Position::new(0, 1))));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Base::level"), SymbolKind::AttributeUsage, base_file, Span::new(Position::new(1, 0), Position::new(1, 1))));
+
+        ws.insert_symbol(Symbol::new(QualifiedName::new("MyMeta"), SymbolKind::AttributeUsage, meta_file.clone(), Span::new(Position::new(0, 0), Position::new(0, 1))));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("MyMeta::level"), SymbolKind::AttributeUsage, meta_file.clone(), redefined_span));
+        ws.relationships.add_edge(RelationshipKind::Redefinition, QualifiedName::new("MyMeta::level"), QualifiedName::new("Base::level"));
+
+        let hover = ws.hover(&meta_file, redefined_span.start).expect("should resolve");
+        assert!(hover.contains("redefines:"));
+        assert!(hover.contains(&format!("file://{}", base_file.display())));
+    }
+
+    #[test]
+    fn a_snapshot_taken_before_an_edit_keeps_reporting_the_pre_edit_state() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::PartDefinition, file.clone(), span));
+
+        let snapshot = ws.snapshot();
+        assert_eq!(snapshot.symbols().len(), 1);
+
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, file, span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::engine"), QualifiedName::new("Engine"));
+
+        assert_eq!(ws.symbols().len(), 2, "the live workspace should see the new symbol");
+        assert_eq!(snapshot.symbols().len(), 1, "the snapshot shouldn't see an edit made after it was taken");
+        assert!(snapshot.relationships().edges(RelationshipKind::Typing, &QualifiedName::new("Vehicle::engine")).is_empty());
+    }
+
+    #[test]
+    fn a_view_s_exposed_members_appear_as_reference_children_only_when_opted_in() {
+        let file = PathBuf::from("VehicleView.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("VehicleView"), SymbolKind::Package, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("VehicleView::overview"), SymbolKind::ViewUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::wheels"), SymbolKind::PartUsage, file, span));
+        ws.record_expose(QualifiedName::new("VehicleView::overview"), QualifiedName::new("Vehicle::engine"));
+        ws.record_expose(QualifiedName::new("VehicleView::overview"), QualifiedName::new("Vehicle::wheels"));
+
+        let without_exposed = ws.document_symbols(&QualifiedName::new("VehicleView"), &DocumentSymbolOptions::default());
+        assert!(without_exposed[0].children.is_empty(), "exposed members are hidden by default");
+
+        let with_exposed = ws.document_symbols(&QualifiedName::new("VehicleView"), &DocumentSymbolOptions { include_exposed: true });
+        let view = &with_exposed[0];
+        assert_eq!(view.children.len(), 2);
+        assert!(view.children.iter().all(|c| c.is_reference));
+        let names: Vec<_> = view.children.iter().map(|c| c.name.as_str()).collect();
+        assert!(names.contains(&"engine"));
+        assert!(names.contains(&"wheels"));
+    }
+}
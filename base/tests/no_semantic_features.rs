@@ -0,0 +1,19 @@
+//! Run with `--no-default-features` to confirm the pure parser/AST/format
+//! surface builds and works without the `semantic` feature (see the
+//! feature-gating notes in `base/src/lib.rs`).
+
+use syster_base::ast::{AstNode, SymbolKind};
+use syster_base::format::{normalize_indentation, FormatOptions};
+use syster_base::span::{Position, Span};
+
+#[test]
+fn ast_and_format_are_usable_without_the_semantic_feature() {
+    let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+    let node = AstNode::new("Vehicle", SymbolKind::PartDefinition, span);
+
+    assert_eq!(node.name, "Vehicle");
+    assert_eq!(node.kind, SymbolKind::PartDefinition);
+
+    let formatted = normalize_indentation("part def Vehicle;", &FormatOptions::default());
+    assert_eq!(formatted, "part def Vehicle;");
+}
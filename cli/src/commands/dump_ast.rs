@@ -0,0 +1,48 @@
+//! `syster dump-ast` — prints a file's AST for regenerating the golden
+//! trees [`syster_base::ast::scan`]'s snapshot test compares against.
+
+use std::path::PathBuf;
+
+use syster_base::ast::scan_block_structure;
+
+pub struct DumpAstArgs {
+    /// File to scan.
+    pub input: PathBuf,
+    /// Strip spans before printing (see
+    /// [`syster_base::ast::AstNode::normalize`]), matching what the golden
+    /// tree is compared against — the form to commit when regenerating one.
+    pub normalized: bool,
+}
+
+pub fn run(args: &DumpAstArgs) -> std::io::Result<()> {
+    let content = std::fs::read_to_string(&args.input)?;
+    let roots = scan_block_structure(&content);
+
+    for root in &roots {
+        if args.normalized {
+            println!("{:#?}", root.normalize());
+        } else {
+            println!("{root:#?}");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("syster-dump-ast-test-{:?}-{name}", std::thread::current().id()));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn dumps_a_file_with_nested_blocks_without_error() {
+        let path = temp_file("vehicle.sysml", "part def Vehicle {\n    part engine;\n}");
+
+        assert!(run(&DumpAstArgs { input: path.clone(), normalized: true }).is_ok());
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -0,0 +1,33 @@
+//! `syster explain` — mirroring `rustc --explain`, prints a human
+//! description and example for a diagnostic rule code.
+
+use syster_base::diagnostics::explain;
+
+pub struct ExplainArgs {
+    pub code: String,
+}
+
+pub fn run(args: &ExplainArgs) -> std::io::Result<()> {
+    match explain(&args.code) {
+        Some(text) => {
+            println!("{text}");
+            Ok(())
+        }
+        None => Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, format!("no such diagnostic code: '{}'", args.code))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_known_code_prints_non_empty_text() {
+        assert!(run(&ExplainArgs { code: "invalid-typing-by-usage".to_string() }).is_ok());
+    }
+
+    #[test]
+    fn an_unknown_code_errors() {
+        assert!(run(&ExplainArgs { code: "no-such-rule".to_string() }).is_err());
+    }
+}
@@ -0,0 +1,79 @@
+//! `syster fmt` — normalizes indentation in a SysML v2 / KerML file,
+//! respecting the nearest `.editorconfig` when present.
+
+use std::path::PathBuf;
+
+use syster_base::format::{format_str, unified_diff, FormatOptions};
+
+pub struct FmtArgs {
+    /// File to format in place.
+    pub input: PathBuf,
+    /// Print the formatted result to stdout instead of writing it back.
+    pub check: bool,
+    /// Print a unified diff of the changes instead of writing them, exiting
+    /// non-zero if the file needs reformatting. For pre-commit hooks and
+    /// code review tooling.
+    pub diff: bool,
+}
+
+/// Formats `args.input` using [`FormatOptions::from_nearest_editorconfig`]
+/// (falling back to [`FormatOptions::default`] when none is found).
+///
+/// With `args.diff`, prints a unified diff and returns an error (so the
+/// process exits non-zero) if the file isn't already formatted; nothing is
+/// written. Otherwise either writes the result back or prints it, depending
+/// on `args.check`.
+pub fn run(args: &FmtArgs) -> std::io::Result<()> {
+    let source = std::fs::read_to_string(&args.input)?;
+    let dir = args.input.parent().unwrap_or_else(|| std::path::Path::new("."));
+    let options = FormatOptions::from_nearest_editorconfig(dir);
+    let formatted = format_str(&source, &options);
+
+    if args.diff {
+        let diff = unified_diff(&source, &formatted, 3);
+        if diff.is_empty() {
+            return Ok(());
+        }
+        print!("{diff}");
+        return Err(std::io::Error::new(std::io::ErrorKind::Other, format!("{} is not formatted", args.input.display())));
+    }
+
+    if args.check {
+        print!("{formatted}");
+    } else {
+        std::fs::write(&args.input, formatted)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("syster-fmt-diff-test-{:?}-{name}", std::thread::current().id()));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn diff_mode_exits_non_zero_and_prints_a_diff_for_an_unformatted_file() {
+        let path = temp_file("unformatted.sysml", "part def Vehicle {\n\tpart engine;\n}");
+
+        let result = run(&FmtArgs { input: path.clone(), check: false, diff: true });
+
+        assert!(result.is_err(), "an unformatted file should make --diff exit non-zero");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "part def Vehicle {\n\tpart engine;\n}", "--diff must not write changes");
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn diff_mode_succeeds_silently_for_an_already_formatted_file() {
+        let path = temp_file("formatted.sysml", "part def Vehicle {\n    part engine;\n}");
+
+        let result = run(&FmtArgs { input: path.clone(), check: false, diff: true });
+
+        assert!(result.is_ok(), "an already-formatted file should make --diff exit zero");
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -0,0 +1,195 @@
+//! `syster lsif` — dump an LSIF graph for a workspace so code browsers (e.g.
+//! Sourcegraph) can serve hover/definition/references without running the
+//! language server live.
+//!
+//! This reuses [`Workspace::hover`], [`Workspace::goto_definition`],
+//! [`Workspace::find_references`] and [`Workspace::moniker`] against a fully
+//! loaded workspace rather than re-deriving cross-reference data.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use serde_json::{json, Value};
+use syster_base::workspace::DirLoadOptions;
+use syster_base::Workspace;
+
+pub struct LsifArgs {
+    pub input: PathBuf,
+    pub output: PathBuf,
+    pub include_globs: Vec<String>,
+    pub exclude_globs: Vec<String>,
+    /// Worker threads for loading `input`; `None` defaults to available
+    /// parallelism, `Some(1)` forces the sequential path.
+    pub threads: Option<usize>,
+}
+
+/// Emits one LSIF vertex/edge object per line (NDJSON) to `args.output`.
+pub fn run(args: &LsifArgs) -> std::io::Result<()> {
+    let options = DirLoadOptions {
+        include_globs: args.include_globs.clone(),
+        exclude_globs: args.exclude_globs.clone(),
+        thread_count: args.threads,
+    };
+    let workspace = Workspace::load_dir_with_options(&args.input, &options)?;
+    let lines = emit(&workspace);
+    let file = File::create(&args.output)?;
+    let mut writer = BufWriter::new(file);
+    for line in lines {
+        writeln!(writer, "{}", line)?;
+    }
+    Ok(())
+}
+
+/// Builds the NDJSON lines of an LSIF dump for `workspace`. Split out from
+/// [`run`] so it can be unit-tested without touching the filesystem.
+pub fn emit(workspace: &Workspace) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut next_id: u64 = 1;
+    let mut fresh_id = move || {
+        let id = next_id;
+        next_id += 1;
+        id
+    };
+
+    let mut doc_ids: HashMap<PathBuf, u64> = HashMap::new();
+    for file in workspace.files() {
+        let id = fresh_id();
+        doc_ids.insert(file.to_path_buf(), id);
+        lines.push(vertex(id, "document", json!({ "uri": uri(file) })));
+    }
+
+    let mut result_set_ids: HashMap<String, u64> = HashMap::new();
+    for symbol in workspace.symbols() {
+        let Some(&doc_id) = doc_ids.get(&symbol.file) else { continue };
+        let range_id = fresh_id();
+        let span = symbol.decl_span;
+        lines.push(vertex(
+            range_id,
+            "range",
+            json!({
+                "start": { "line": span.start.line, "character": span.start.column },
+                "end": { "line": span.end.line, "character": span.end.column },
+            }),
+        ));
+        lines.push(edge(fresh_id(), "contains", doc_id, &[range_id]));
+
+        let key = symbol.qualified_name.to_string();
+        let result_set_id = *result_set_ids.entry(key.clone()).or_insert_with(&mut fresh_id);
+        lines.push(edge(fresh_id(), "next", range_id, &[result_set_id]));
+
+        if let Some(hover) = workspace.hover(&symbol.file, span.start) {
+            let hover_id = fresh_id();
+            lines.push(vertex(
+                hover_id,
+                "hoverResult",
+                json!({ "result": { "contents": { "kind": "markdown", "value": hover } } }),
+            ));
+            lines.push(edge(fresh_id(), "textDocument/hover", result_set_id, &[hover_id]));
+        }
+
+        if let Some(def) = workspace.goto_definition(&symbol.file, span.start) {
+            if let Some(&def_doc_id) = doc_ids.get(&def.file) {
+                let def_result_id = fresh_id();
+                lines.push(vertex(def_result_id, "definitionResult", json!({})));
+                lines.push(edge(fresh_id(), "textDocument/definition", result_set_id, &[def_result_id]));
+                lines.push(edge(fresh_id(), "item", def_result_id, &[range_id]));
+                let _ = def_doc_id;
+            }
+        }
+
+        let refs = workspace.find_references(&symbol.file, span.start);
+        if !refs.is_empty() {
+            let ref_result_id = fresh_id();
+            lines.push(vertex(ref_result_id, "referenceResult", json!({})));
+            lines.push(edge(fresh_id(), "textDocument/references", result_set_id, &[ref_result_id]));
+            lines.push(edge(fresh_id(), "item", ref_result_id, &[range_id]));
+        }
+
+        if let Some(moniker) = workspace.moniker(&symbol.file, span.start) {
+            let moniker_id = fresh_id();
+            lines.push(vertex(moniker_id, "moniker", json!({ "identifier": moniker, "kind": "export" })));
+            lines.push(edge(fresh_id(), "moniker", result_set_id, &[moniker_id]));
+        }
+    }
+
+    lines
+}
+
+fn uri(path: &Path) -> String {
+    format!("file://{}", path.display())
+}
+
+fn vertex(id: u64, label: &str, extra: Value) -> String {
+    let mut obj = json!({ "id": id, "type": "vertex", "label": label });
+    merge(&mut obj, extra);
+    obj.to_string()
+}
+
+fn edge(id: u64, label: &str, out_v: u64, in_vs: &[u64]) -> String {
+    let obj = json!({
+        "id": id,
+        "type": "edge",
+        "label": label,
+        "outV": out_v,
+        "inVs": in_vs,
+    });
+    obj.to_string()
+}
+
+fn merge(base: &mut Value, extra: Value) {
+    if let (Value::Object(base_map), Value::Object(extra_map)) = (base, extra) {
+        for (k, v) in extra_map {
+            base_map.insert(k, v);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use syster_base::semantic::{QualifiedName, Symbol, SymbolKind};
+    use syster_base::{Position, Span};
+
+    fn sample_workspace() -> Workspace {
+        let mut ws = Workspace::default();
+        let file = PathBuf::from("Vehicle.sysml");
+        ws.insert_symbol(Symbol::new(
+            QualifiedName::new("Vehicle"),
+            SymbolKind::PartDefinition,
+            file.clone(),
+            Span::new(Position::new(0, 9), Position::new(0, 16)),
+        ));
+        ws.insert_symbol(Symbol::new(
+            QualifiedName::new("Vehicle"),
+            SymbolKind::PartDefinition,
+            file,
+            Span::new(Position::new(5, 4), Position::new(5, 11)),
+        ));
+        ws
+    }
+
+    #[test]
+    fn emits_valid_ndjson_with_definition_and_reference_edges() {
+        let ws = sample_workspace();
+        let lines = emit(&ws);
+        assert!(!lines.is_empty());
+
+        let mut saw_definition_edge = false;
+        let mut saw_reference_edge = false;
+        for line in &lines {
+            let value: Value = serde_json::from_str(line).expect("each LSIF line must be valid JSON");
+            if value["label"] == "textDocument/definition" {
+                saw_definition_edge = true;
+            }
+            if value["label"] == "textDocument/references" {
+                saw_reference_edge = true;
+            }
+        }
+
+        assert!(saw_definition_edge, "expected at least one definition edge");
+        assert!(saw_reference_edge, "expected at least one reference edge");
+    }
+}
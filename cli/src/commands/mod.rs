@@ -0,0 +1,10 @@
+//! Subcommands for the `syster` CLI.
+
+pub mod dump_ast;
+pub mod explain;
+pub mod fmt;
+pub mod lsif;
+pub mod rename;
+pub mod trace;
+pub mod validate;
+pub mod watch;
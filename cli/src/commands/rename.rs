@@ -0,0 +1,84 @@
+//! `syster rename` — preview (or, eventually, apply) a cross-file symbol
+//! rename. `--dry-run` prints every location `Workspace::find_references`
+//! reports for `--name`'s declaration — the same set a real rename would
+//! touch — without writing anything; there's no text-rewriting applier in
+//! this tree yet, so omitting `--dry-run` is an error rather than a silent
+//! no-op.
+
+use std::path::PathBuf;
+
+use syster_base::semantic::qualified_name::QualifiedName;
+use syster_base::workspace::DirLoadOptions;
+use syster_base::Workspace;
+
+pub struct RenameArgs {
+    pub input: PathBuf,
+    /// The qualified name of the symbol to rename, e.g. `Vehicle::engine`.
+    pub name: String,
+    /// The name it would be renamed to.
+    pub to: String,
+    /// Print the locations that would change instead of renaming anything.
+    pub dry_run: bool,
+    /// Worker threads for loading `input`; `None` defaults to available
+    /// parallelism, `Some(1)` forces the sequential path.
+    pub threads: Option<usize>,
+}
+
+pub fn run(args: &RenameArgs) -> std::io::Result<()> {
+    if !args.dry_run {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "syster rename currently only supports --dry-run; there's no text-rewriting applier in this tree yet",
+        ));
+    }
+
+    let options = DirLoadOptions { thread_count: args.threads, ..Default::default() };
+    let workspace = Workspace::load_dir_with_options(&args.input, &options)?;
+    let name = QualifiedName::new(args.name.as_str());
+
+    let Some(declaration) = workspace.symbol_by_qualified_name(&name) else {
+        return Err(std::io::Error::new(std::io::ErrorKind::NotFound, format!("'{}' does not resolve", args.name)));
+    };
+
+    let locations = workspace.find_references(&declaration.file, declaration.range().start);
+
+    println!("renaming '{}' to '{}' would touch {} location(s):", args.name, args.to, locations.len());
+    for symbol in &locations {
+        println!("  {} {}", syster_base::format_span(symbol.decl_span), symbol.file.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syster_base::semantic::{RelationshipKind, Symbol, SymbolKind};
+    use syster_base::span::{Position, Span};
+
+    #[test]
+    fn dry_run_reports_every_location_without_touching_the_workspace() {
+        let mut ws = Workspace::default();
+        let file = PathBuf::from("Vehicle.sysml");
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, file.clone(), Span::new(Position::new(0, 0), Position::new(0, 6))));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::p"), SymbolKind::PartUsage, file, Span::new(Position::new(1, 0), Position::new(1, 1))));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::p"), QualifiedName::new("Vehicle::engine"));
+
+        let declaration = ws.symbol_by_qualified_name(&QualifiedName::new("Vehicle::engine")).unwrap();
+        let locations = ws.find_references(&declaration.file, declaration.range().start);
+
+        assert_eq!(locations.len(), 2);
+    }
+
+    #[test]
+    fn omitting_dry_run_is_an_error_since_there_is_no_applier() {
+        let dir = std::env::temp_dir().join(format!("syster-rename-cli-test-{:?}", std::thread::current().id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("Vehicle.sysml"), "part def Vehicle { part engine; }").unwrap();
+
+        let args = RenameArgs { input: dir.clone(), name: "Vehicle::engine".into(), to: "motor".into(), dry_run: false, threads: Some(1) };
+        let err = run(&args).expect_err("no text-rewriting applier exists yet");
+        assert_eq!(err.kind(), std::io::ErrorKind::Unsupported);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,37 @@
+//! `syster trace` — export a requirement `subject`/`about` traceability
+//! matrix as JSON.
+
+use std::path::PathBuf;
+
+use serde_json::json;
+use syster_base::workspace::DirLoadOptions;
+use syster_base::Workspace;
+
+pub struct TraceArgs {
+    pub input: PathBuf,
+    /// Worker threads for loading `input`; `None` defaults to available
+    /// parallelism, `Some(1)` forces the sequential path.
+    pub threads: Option<usize>,
+}
+
+pub fn run(args: &TraceArgs) -> std::io::Result<()> {
+    let options = DirLoadOptions { thread_count: args.threads, ..Default::default() };
+    let workspace = Workspace::load_dir_with_options(&args.input, &options)?;
+    let rows: Vec<_> = workspace
+        .requirement_traceability()
+        .into_iter()
+        .map(|row| {
+            let location = workspace.symbol_by_qualified_name(&row.requirement).map(|s| syster_base::format_span(s.decl_span));
+            json!({
+                "requirement": row.requirement.to_string(),
+                "location": location,
+                "subject": row.subject.map(|s| s.to_string()),
+                "about": row.about.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            })
+        })
+        .collect();
+    let text = serde_json::to_string_pretty(&rows)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    println!("{text}");
+    Ok(())
+}
@@ -0,0 +1,235 @@
+//! `syster validate` — runs the full semantic validation suite (typing,
+//! connection-end, specialization-cycle, unresolved-reference,
+//! framed-concern, and conflicting-redefinition checks)
+//! and prints a report grouped by rule code, for CI gating. Distinct from
+//! the opt-in heuristic checks (`check_empty_packages`, `check_stdlib_shadowing`,
+//! ...), which a caller enables selectively: this always runs the
+//! correctness-oriented passes and exits non-zero if any error-severity
+//! finding exists.
+
+use std::path::PathBuf;
+
+use std::time::Instant;
+
+use clap::ValueEnum;
+use serde_json::json;
+use syster_base::diagnostics::{run_analysis_scoped, AnalysisMode, Severity};
+use syster_base::semantic::qualified_name::QualifiedName;
+use syster_base::span::span_to_line_col;
+use syster_base::workspace::DirLoadOptions;
+use syster_base::Workspace;
+
+/// `--format` for [`run`]'s report.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ReportFormat {
+    /// Human-readable, one finding per line.
+    Text,
+    /// Findings and the summary as a single JSON object.
+    Json,
+    /// [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/) JSON,
+    /// for ingestion by security/quality dashboards that expect it.
+    Sarif,
+}
+
+pub struct ValidateArgs {
+    pub input: PathBuf,
+    /// Output format for the report.
+    pub format: ReportFormat,
+    /// Worker threads for loading `input`; `None` defaults to available
+    /// parallelism, `Some(1)` forces the sequential path.
+    pub threads: Option<usize>,
+    /// Restrict the report to these root packages and whatever they
+    /// transitively import (`--root A::B`, repeatable). Empty means
+    /// unrestricted, matching [`run_analysis_scoped`]'s behavior for an
+    /// empty root list.
+    pub root_packages: Vec<String>,
+    /// Only report parse errors, skipping every semantic pass
+    /// ([`AnalysisMode::SyntaxOnly`]) — for a fast editor-on-save or CI
+    /// check that only cares whether the file is well-formed.
+    pub syntax_only: bool,
+    /// Print how long the analysis phase took (and which mode ran), plus
+    /// the workspace's approximate memory usage, after the report.
+    pub stats: bool,
+}
+
+pub fn run(args: &ValidateArgs) -> std::io::Result<()> {
+    let options = DirLoadOptions { thread_count: args.threads, ..Default::default() };
+    let workspace = Workspace::load_dir_with_options(&args.input, &options)?;
+    let roots: Vec<QualifiedName> = args.root_packages.iter().map(|name| QualifiedName::new(name.as_str())).collect();
+    let mode = if args.syntax_only { AnalysisMode::SyntaxOnly } else { AnalysisMode::Full };
+
+    let started = Instant::now();
+    let report = run_analysis_scoped(&workspace, mode, &roots);
+    let elapsed = started.elapsed();
+
+    match args.format {
+        ReportFormat::Text => print_text_report(&report),
+        ReportFormat::Json => print_json_report(&report)?,
+        ReportFormat::Sarif => print_sarif_report(&report)?,
+    }
+
+    if args.stats {
+        let mode_label = if args.syntax_only { "syntax-only" } else { "full" };
+        println!("analysis ({mode_label}) completed in {:.3}ms", elapsed.as_secs_f64() * 1000.0);
+        println!("workspace: ~{} bytes (approximate)", workspace.estimated_memory_bytes());
+    }
+
+    if report.has_errors() {
+        let error_count = report.diagnostics.iter().filter(|d| d.severity == Severity::Error).count();
+        return Err(std::io::Error::new(std::io::ErrorKind::Other, format!("{error_count} error-severity finding(s)")));
+    }
+    Ok(())
+}
+
+fn print_text_report(report: &syster_base::diagnostics::ValidationReport) {
+    for d in &report.diagnostics {
+        println!("{} [{}] {}: {}", syster_base::format_span(d.span), severity_label(d.severity), d.code, d.message);
+    }
+    for s in report.summary() {
+        println!("{:>5}  {:<8} {}", s.count, severity_label(s.severity), s.code);
+    }
+}
+
+fn print_json_report(report: &syster_base::diagnostics::ValidationReport) -> std::io::Result<()> {
+    let findings: Vec<_> = report
+        .diagnostics
+        .iter()
+        .map(|d| {
+            json!({
+                "code": d.code,
+                "severity": severity_label(d.severity),
+                "message": d.message,
+                "file": d.file.display().to_string(),
+                "location": syster_base::format_span(d.span),
+            })
+        })
+        .collect();
+    let summary: Vec<_> = report
+        .summary()
+        .into_iter()
+        .map(|s| json!({ "code": s.code, "severity": severity_label(s.severity), "count": s.count }))
+        .collect();
+
+    let text = serde_json::to_string_pretty(&json!({ "findings": findings, "summary": summary }))
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    println!("{text}");
+    Ok(())
+}
+
+/// Builds the SARIF 2.1.0 report `syster validate --format sarif` prints:
+/// one `run` with one `result` per [`syster_base::diagnostics::Diagnostic`],
+/// keyed to its rule code and physical location. Split out from
+/// [`print_sarif_report`] so the value itself (not just stdout output) is
+/// directly assertable in tests.
+fn sarif_report_value(report: &syster_base::diagnostics::ValidationReport) -> serde_json::Value {
+    let results: Vec<_> = report
+        .diagnostics
+        .iter()
+        .map(|d| {
+            let (start, end) = span_to_line_col(d.span);
+            json!({
+                "ruleId": d.code,
+                "level": sarif_level(d.severity),
+                "message": { "text": d.message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": d.file.display().to_string() },
+                        "region": {
+                            "startLine": start.line + 1,
+                            "startColumn": start.column + 1,
+                            "endLine": end.line + 1,
+                            "endColumn": end.column + 1,
+                        },
+                    },
+                }],
+            })
+        })
+        .collect();
+
+    json!({
+        "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": { "driver": { "name": "syster", "informationUri": "https://github.com/mohammed-j-mahmoud/syster" } },
+            "results": results,
+        }],
+    })
+}
+
+fn print_sarif_report(report: &syster_base::diagnostics::ValidationReport) -> std::io::Result<()> {
+    let text = serde_json::to_string_pretty(&sarif_report_value(report)).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    println!("{text}");
+    Ok(())
+}
+
+/// Maps [`Severity`] to a SARIF `result.level`. SARIF has no `hint`/`note`
+/// distinction as fine-grained as ours, so [`Severity::Hint`] and
+/// [`Severity::Information`] both collapse to `note`.
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Information | Severity::Hint => "note",
+    }
+}
+
+fn severity_label(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Information => "info",
+        Severity::Hint => "hint",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syster_base::diagnostics::Diagnostic;
+    use syster_base::span::{Position, Span};
+
+    fn sample_report() -> syster_base::diagnostics::ValidationReport {
+        syster_base::diagnostics::ValidationReport {
+            diagnostics: vec![
+                Diagnostic::new(
+                    Severity::Error,
+                    "unresolved-reference",
+                    "'Engine' does not resolve",
+                    PathBuf::from("Vehicle.sysml"),
+                    Span::new(Position::new(2, 4), Position::new(2, 10)),
+                ),
+                Diagnostic::new(
+                    Severity::Warning,
+                    "empty-package",
+                    "'Unused' has no members",
+                    PathBuf::from("Vehicle.sysml"),
+                    Span::new(Position::new(0, 0), Position::new(0, 6)),
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn sarif_report_has_every_required_top_level_field() {
+        let sarif = sarif_report_value(&sample_report());
+
+        assert_eq!(sarif["version"], "2.1.0");
+        let run = &sarif["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "syster");
+        assert_eq!(run["results"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn sarif_result_carries_its_rule_code_level_and_physical_location() {
+        let sarif = sarif_report_value(&sample_report());
+
+        let result = &sarif["runs"][0]["results"][0];
+        assert_eq!(result["ruleId"], "unresolved-reference");
+        assert_eq!(result["level"], "error");
+        assert_eq!(result["message"]["text"], "'Engine' does not resolve");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "Vehicle.sysml");
+        assert_eq!(location["region"]["startLine"], 3);
+        assert_eq!(location["region"]["startColumn"], 5);
+    }
+}
@@ -0,0 +1,222 @@
+//! `syster watch` — polls the input directory for file modifications and
+//! re-runs validation whenever something changes, emitting one
+//! newline-delimited JSON object per pass with `--json` for editor plugins
+//! that don't speak LSP. This tree has no `notify` (or other kernel
+//! filesystem-event) dependency, so [`run`]'s loop polls each loaded
+//! file's mtime on an interval ([`POLL_INTERVAL`]) rather than subscribing
+//! to real filesystem events — coarser-grained, but it's a real watch
+//! loop: [`run`] never returns on its own, the same way `tsc --watch`
+//! doesn't. [`changed_since`], [`diagnostic_delta`], and [`watch_line`],
+//! the pieces of a single poll cycle, are exercised directly by the tests
+//! below without needing a real sleep/filesystem round-trip.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use serde_json::json;
+use syster_base::diagnostics::{Diagnostic, ValidationReport};
+use syster_base::span::span_to_line_col;
+use syster_base::workspace::DirLoadOptions;
+use syster_base::Workspace;
+
+pub struct WatchArgs {
+    pub input: PathBuf,
+    /// Emit newline-delimited JSON instead of human-readable text.
+    pub json: bool,
+    /// Worker threads for loading `input`; `None` defaults to available
+    /// parallelism, `Some(1)` forces the sequential path.
+    pub threads: Option<usize>,
+}
+
+/// How long [`run`]'s poll loop sleeps between checking file modification
+/// times.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Snapshots every loaded file's modification time, for [`changed_since`]
+/// to diff against on the next poll. A file whose mtime can't be read
+/// (removed mid-poll, permissions) is dropped from the snapshot rather
+/// than treated as an error — the next poll picks it back up once it's
+/// readable again.
+fn snapshot_mtimes(workspace: &Workspace) -> HashMap<PathBuf, SystemTime> {
+    workspace.files().filter_map(|f| std::fs::metadata(f).and_then(|m| m.modified()).ok().map(|mtime| (f.to_path_buf(), mtime))).collect()
+}
+
+/// Which files in `current` are new or have a different mtime than in
+/// `previous` — the file-level granularity [`run`]'s poll loop reports as
+/// `changedFiles`. A file that disappeared entirely (present in `previous`,
+/// absent from `current`) isn't reported here; it shows up instead as its
+/// diagnostics resolving in the next [`diagnostic_delta`].
+fn changed_since(previous: &HashMap<PathBuf, SystemTime>, current: &HashMap<PathBuf, SystemTime>) -> Vec<PathBuf> {
+    current.iter().filter(|(file, mtime)| previous.get(file.as_path()) != Some(mtime)).map(|(file, _)| file.clone()).collect()
+}
+
+/// The new and resolved diagnostics between two [`ValidationReport`]s for
+/// the same workspace, keyed by `(code, file, span, message)` — cheap and
+/// exact, since diagnostics don't carry a stable id of their own.
+pub struct DiagnosticDelta<'a> {
+    pub new: Vec<&'a Diagnostic>,
+    pub resolved: Vec<&'a Diagnostic>,
+}
+
+fn diagnostic_key(d: &Diagnostic) -> (&'static str, &std::path::Path, syster_base::span::Span, &str) {
+    (d.code, d.file.as_path(), d.span, d.message.as_str())
+}
+
+pub fn diagnostic_delta<'a>(before: &'a ValidationReport, after: &'a ValidationReport) -> DiagnosticDelta<'a> {
+    let before_keys: std::collections::HashSet<_> = before.diagnostics.iter().map(diagnostic_key).collect();
+    let after_keys: std::collections::HashSet<_> = after.diagnostics.iter().map(diagnostic_key).collect();
+
+    DiagnosticDelta {
+        new: after.diagnostics.iter().filter(|d| !before_keys.contains(&diagnostic_key(d))).collect(),
+        resolved: before.diagnostics.iter().filter(|d| !after_keys.contains(&diagnostic_key(d))).collect(),
+    }
+}
+
+fn diagnostic_json(d: &Diagnostic) -> serde_json::Value {
+    let (start, end) = span_to_line_col(d.span);
+    json!({
+        "code": d.code,
+        "message": d.message,
+        "file": d.file.display().to_string(),
+        "range": {
+            "start": { "line": start.line, "column": start.column },
+            "end": { "line": end.line, "column": end.column },
+        },
+    })
+}
+
+/// One NDJSON line for a single analysis pass over `changed_files`.
+pub fn watch_line(changed_files: &[PathBuf], delta: &DiagnosticDelta) -> String {
+    let value = json!({
+        "changedFiles": changed_files.iter().map(|f| f.display().to_string()).collect::<Vec<_>>(),
+        "diagnostics": {
+            "new": delta.new.iter().map(|d| diagnostic_json(d)).collect::<Vec<_>>(),
+            "resolved": delta.resolved.iter().map(|d| diagnostic_json(d)).collect::<Vec<_>>(),
+        },
+    });
+    value.to_string()
+}
+
+fn print_pass(args: &WatchArgs, changed_files: &[PathBuf], delta: &DiagnosticDelta) {
+    if args.json {
+        println!("{}", watch_line(changed_files, delta));
+        return;
+    }
+    for d in &delta.new {
+        println!("{} [new] {}: {}", syster_base::format_span(d.span), d.code, d.message);
+    }
+    for d in &delta.resolved {
+        println!("{} [resolved] {}: {}", syster_base::format_span(d.span), d.code, d.message);
+    }
+}
+
+/// Loads `args.input`, reports its initial diagnostics as a first pass,
+/// then every [`POLL_INTERVAL`] reloads the whole workspace and compares
+/// mtimes ([`changed_since`]): on a change, validation reruns and the
+/// delta against the previous pass ([`diagnostic_delta`]) is printed. Runs
+/// until killed — there's no exit condition, the same as any other watch
+/// mode.
+pub fn run(args: &WatchArgs) -> std::io::Result<()> {
+    let options = DirLoadOptions { thread_count: args.threads, ..Default::default() };
+    let mut workspace = Workspace::load_dir_with_options(&args.input, &options)?;
+    let mut mtimes = snapshot_mtimes(&workspace);
+    let mut report = syster_base::diagnostics::run_analysis_scoped(&workspace, syster_base::diagnostics::AnalysisMode::Full, &[]);
+
+    if !args.json {
+        println!("watching {} ({} file(s) loaded)", args.input.display(), mtimes.len());
+    }
+    let initial_files: Vec<PathBuf> = mtimes.keys().cloned().collect();
+    print_pass(args, &initial_files, &diagnostic_delta(&ValidationReport::default(), &report));
+
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+
+        workspace = Workspace::load_dir_with_options(&args.input, &options)?;
+        let current_mtimes = snapshot_mtimes(&workspace);
+        let changed_files = changed_since(&mtimes, &current_mtimes);
+        if changed_files.is_empty() {
+            continue;
+        }
+        mtimes = current_mtimes;
+
+        let new_report = syster_base::diagnostics::run_analysis_scoped(&workspace, syster_base::diagnostics::AnalysisMode::Full, &[]);
+        print_pass(args, &changed_files, &diagnostic_delta(&report, &new_report));
+        report = new_report;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syster_base::diagnostics::Severity;
+    use syster_base::span::{Position, Span};
+
+    fn diag(code: &'static str, message: &str) -> Diagnostic {
+        Diagnostic::new(Severity::Error, code, message, PathBuf::from("Vehicle.sysml"), Span::new(Position::new(2, 4), Position::new(2, 10)))
+    }
+
+    #[test]
+    fn changed_since_reports_new_and_modified_files_but_not_untouched_ones() {
+        let t0 = SystemTime::UNIX_EPOCH;
+        let t1 = t0 + Duration::from_secs(1);
+        let mut previous = HashMap::new();
+        previous.insert(PathBuf::from("Vehicle.sysml"), t0);
+        previous.insert(PathBuf::from("Untouched.sysml"), t0);
+        let mut current = HashMap::new();
+        current.insert(PathBuf::from("Vehicle.sysml"), t1);
+        current.insert(PathBuf::from("Untouched.sysml"), t0);
+        current.insert(PathBuf::from("New.sysml"), t0);
+
+        let mut changed = changed_since(&previous, &current);
+        changed.sort();
+
+        assert_eq!(changed, vec![PathBuf::from("New.sysml"), PathBuf::from("Vehicle.sysml")]);
+    }
+
+    #[test]
+    fn changed_since_is_empty_when_no_mtime_moved() {
+        let t0 = SystemTime::UNIX_EPOCH;
+        let mut previous = HashMap::new();
+        previous.insert(PathBuf::from("Vehicle.sysml"), t0);
+        let current = previous.clone();
+
+        assert!(changed_since(&previous, &current).is_empty());
+    }
+
+    #[test]
+    fn a_newly_introduced_diagnostic_is_reported_as_new_not_resolved() {
+        let before = ValidationReport::default();
+        let after = ValidationReport { diagnostics: vec![diag("unresolved-reference", "'Engine' does not resolve")] };
+
+        let delta = diagnostic_delta(&before, &after);
+
+        assert_eq!(delta.new.len(), 1);
+        assert!(delta.resolved.is_empty());
+    }
+
+    #[test]
+    fn a_fixed_diagnostic_is_reported_as_resolved_not_new() {
+        let before = ValidationReport { diagnostics: vec![diag("unresolved-reference", "'Engine' does not resolve")] };
+        let after = ValidationReport::default();
+
+        let delta = diagnostic_delta(&before, &after);
+
+        assert!(delta.new.is_empty());
+        assert_eq!(delta.resolved.len(), 1);
+    }
+
+    #[test]
+    fn watch_line_is_a_single_json_object_with_the_expected_delta_shape() {
+        let before = ValidationReport::default();
+        let after = ValidationReport { diagnostics: vec![diag("unresolved-reference", "'Engine' does not resolve")] };
+        let delta = diagnostic_delta(&before, &after);
+
+        let line = watch_line(&[PathBuf::from("Vehicle.sysml")], &delta);
+        let value: serde_json::Value = serde_json::from_str(&line).expect("watch_line must emit one valid JSON object per line");
+
+        assert_eq!(value["changedFiles"][0], "Vehicle.sysml");
+        assert_eq!(value["diagnostics"]["new"][0]["code"], "unresolved-reference");
+        assert!(value["diagnostics"]["resolved"].as_array().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,154 @@
+//! `syster` — command-line tooling for SysML v2 / KerML models.
+
+mod commands;
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use commands::dump_ast::{self, DumpAstArgs};
+use commands::explain::{self, ExplainArgs};
+use commands::fmt::{self, FmtArgs};
+use commands::lsif::{self, LsifArgs};
+use commands::rename::{self, RenameArgs};
+use commands::trace::{self, TraceArgs};
+use commands::validate::{self, ReportFormat, ValidateArgs};
+use commands::watch::{self, WatchArgs};
+
+#[derive(Parser)]
+#[command(name = "syster", about = "SysML v2 / KerML tooling")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Worker threads for loading the workspace. Defaults to available
+    /// parallelism; pass `1` to force deterministic sequential loading
+    /// (useful in constrained CI environments).
+    #[arg(long, global = true)]
+    threads: Option<usize>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Emit an LSIF graph for a workspace.
+    Lsif {
+        /// Directory or file to load as a workspace.
+        input: PathBuf,
+        /// Path to write the NDJSON LSIF dump to.
+        #[arg(short, long, default_value = "dump.lsif")]
+        output: PathBuf,
+        /// Only load files matching one of these glob patterns (relative
+        /// to `input`). May be passed multiple times.
+        #[arg(long = "include-glob")]
+        include_globs: Vec<String>,
+        /// Skip files matching one of these glob patterns, even if they
+        /// also match `--include-glob`. May be passed multiple times.
+        #[arg(long = "exclude-glob")]
+        exclude_globs: Vec<String>,
+    },
+    /// Export a requirement subject/about traceability matrix as JSON.
+    Trace { input: PathBuf },
+    /// Normalize indentation in a SysML v2 / KerML file, honoring the
+    /// nearest `.editorconfig` when present.
+    Fmt {
+        /// File to format.
+        input: PathBuf,
+        /// Print the formatted result to stdout instead of writing it back.
+        #[arg(long)]
+        check: bool,
+        /// Print a unified diff instead of writing, exiting non-zero if the
+        /// file needs reformatting.
+        #[arg(long, conflicts_with = "check")]
+        diff: bool,
+    },
+    /// Run the full semantic validation suite and report findings grouped
+    /// by rule code. For CI gating: exits non-zero if any error-severity
+    /// finding exists.
+    Validate {
+        /// Directory or file to load as a workspace.
+        input: PathBuf,
+        /// Output format: `text` (default), `json`, or `sarif` (SARIF
+        /// 2.1.0, for security/quality dashboards).
+        #[arg(long, value_enum, default_value = "text")]
+        format: ReportFormat,
+        /// Restrict the report to this root package and whatever it
+        /// transitively imports (e.g. `A::B`). May be passed multiple
+        /// times; omitting it reports on the whole workspace.
+        #[arg(long = "root")]
+        root_packages: Vec<String>,
+        /// Only report parse errors, skipping every semantic pass — fast
+        /// syntax-only validation for editors-on-save or CI.
+        #[arg(long = "syntax-only")]
+        syntax_only: bool,
+        /// Print how long analysis took (and which mode ran), plus the
+        /// workspace's approximate memory usage, after the report.
+        #[arg(long)]
+        stats: bool,
+    },
+    /// Print a human description and example for a diagnostic rule code.
+    Explain {
+        /// The diagnostic code to explain, e.g. `unresolved-reference`.
+        code: String,
+    },
+    /// Preview (or, eventually, apply) a cross-file symbol rename.
+    Rename {
+        /// Directory or file to load as a workspace.
+        input: PathBuf,
+        /// The qualified name of the symbol to rename, e.g. `Vehicle::engine`.
+        #[arg(long)]
+        name: String,
+        /// The name it would be renamed to.
+        #[arg(long)]
+        to: String,
+        /// Print the locations that would change instead of renaming
+        /// anything — currently required, since there's no applier yet.
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Validate, then poll for file changes and report the diagnostic delta
+    /// after each change until killed; `--json` emits one newline-delimited
+    /// JSON object per analysis pass instead of human-readable text, for
+    /// lightweight editor plugins that don't speak LSP.
+    Watch {
+        /// Directory or file to load as a workspace.
+        input: PathBuf,
+        /// Emit each pass's diagnostic delta as one line of JSON.
+        #[arg(long)]
+        json: bool,
+    },
+    /// Print a file's AST, for regenerating the golden trees
+    /// `syster_base::ast::scan`'s snapshot test compares against.
+    DumpAst {
+        /// File to scan.
+        input: PathBuf,
+        /// Strip spans before printing — the form to commit as a golden.
+        #[arg(long)]
+        normalized: bool,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let threads = cli.threads;
+    let result = match cli.command {
+        Command::Lsif { input, output, include_globs, exclude_globs } => {
+            lsif::run(&LsifArgs { input, output, include_globs, exclude_globs, threads })
+        }
+        Command::Trace { input } => trace::run(&TraceArgs { input, threads }),
+        Command::Fmt { input, check, diff } => fmt::run(&FmtArgs { input, check, diff }),
+        Command::Validate { input, format, root_packages, syntax_only, stats } => {
+            validate::run(&ValidateArgs { input, format, threads, root_packages, syntax_only, stats })
+        }
+        Command::Rename { input, name, to, dry_run } => rename::run(&RenameArgs { input, name, to, dry_run, threads }),
+        Command::Watch { input, json } => watch::run(&WatchArgs { input, json, threads }),
+        Command::Explain { code } => explain::run(&ExplainArgs { code }),
+        Command::DumpAst { input, normalized } => dump_ast::run(&DumpAstArgs { input, normalized }),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
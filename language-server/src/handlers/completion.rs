@@ -0,0 +1,672 @@
+//! `textDocument/completion`.
+
+use lsp_types::{CompletionItem, CompletionItemKind, CompletionOptions, Documentation, InsertTextFormat, MarkupContent, MarkupKind, Position as LspPosition, Range, TextEdit};
+use syster_base::semantic::QualifiedName;
+use syster_base::Workspace;
+
+/// Capabilities to advertise for `textDocument/completion` during
+/// `initialize`. `resolve_provider` is set so doc-comment assembly (which
+/// can mean reading another file) happens lazily on `completionItem/resolve`
+/// rather than up front for every item in the list.
+pub fn completion_options() -> CompletionOptions {
+    CompletionOptions { resolve_provider: Some(true), ..Default::default() }
+}
+
+/// `completionItem/resolve` — fills in `documentation` from the doc comment
+/// attached to the item's qualified name (stashed in `detail` by
+/// [`member_access_completions`]). Left out of the initial completion list
+/// so it stays fast even when a scope has many documented members.
+pub fn resolve_completion_item(workspace: &Workspace, mut item: CompletionItem) -> CompletionItem {
+    let Some(detail) = item.detail.clone() else { return item };
+    if let Some(doc) = workspace.doc_comments.get(&QualifiedName::new(detail)) {
+        item.documentation =
+            Some(Documentation::MarkupContent(MarkupContent { kind: MarkupKind::Markdown, value: doc.to_string() }));
+    }
+    item
+}
+
+/// Member-access completion triggered by typing `.` after a feature chain
+/// segment, e.g. `engine.` — offers `engine`'s members (including any
+/// inherited from its specialization chain) rather than falling back to
+/// keyword/top-level completion.
+pub fn member_access_completions(workspace: &Workspace, scope: &QualifiedName) -> Vec<CompletionItem> {
+    workspace
+        .members_including_inherited(scope)
+        .into_iter()
+        .map(|symbol| CompletionItem {
+            label: symbol.name().to_string(),
+            kind: Some(completion_kind(symbol.kind)),
+            detail: Some(symbol.qualified_name.to_string()),
+            deprecated: Some(workspace.is_deprecated(&symbol.qualified_name)),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Completion after `= ` on a feature typed by an enum, e.g. `attribute c
+/// : Color = ` — resolves `feature`'s declared type and, if it's an
+/// [`EnumerationDefinition`](syster_base::semantic::SymbolKind::EnumerationDefinition),
+/// offers its `enumerated_value`s as qualified references (`Color::red`),
+/// since a feature-value position expects a reference to the enum member,
+/// not a bare name that would shadow-resolve against the wrong scope.
+/// Empty for anything not typed by an enum.
+pub fn enum_value_completions(workspace: &Workspace, feature: &QualifiedName) -> Vec<CompletionItem> {
+    use syster_base::semantic::{RelationshipKind, SymbolKind};
+
+    let Some(ty) = workspace.relationships.edges(RelationshipKind::Typing, feature).first() else { return Vec::new() };
+    if workspace.symbol_by_qualified_name(ty).map(|s| s.kind) != Some(SymbolKind::EnumerationDefinition) {
+        return Vec::new();
+    }
+
+    workspace
+        .children_of(ty)
+        .into_iter()
+        .filter(|symbol| symbol.kind == SymbolKind::EnumeratedValue)
+        .map(|symbol| CompletionItem {
+            label: symbol.qualified_name.to_string(),
+            kind: Some(completion_kind(symbol.kind)),
+            detail: Some(symbol.qualified_name.to_string()),
+            deprecated: Some(workspace.is_deprecated(&symbol.qualified_name)),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Completion inside a `metadata def`/usage body after `ref :>> `, e.g.
+/// `metadata MyMeta : Base { ref :>> | }` — offers the attribute names
+/// `scope` inherits from its type (`Base`, via `scope`'s `Typing` edge)
+/// that can still be redefined, using the same
+/// [`Workspace::members_including_inherited`] feature-set traversal
+/// [`member_access_completions`] uses for `.` completion, filtered to
+/// attributes and excluding any `scope` already redefines.
+pub fn redefinition_completions(workspace: &Workspace, scope: &QualifiedName) -> Vec<CompletionItem> {
+    use syster_base::semantic::{RelationshipKind, SymbolKind};
+
+    let Some(ty) = workspace.relationships.edges(RelationshipKind::Typing, scope).first() else { return Vec::new() };
+    let already_redefined: std::collections::HashSet<&QualifiedName> = workspace
+        .children_of(scope)
+        .into_iter()
+        .flat_map(|child| workspace.relationships.edges(RelationshipKind::Redefinition, &child.qualified_name))
+        .collect();
+
+    workspace
+        .members_including_inherited(ty)
+        .into_iter()
+        .filter(|symbol| symbol.kind == SymbolKind::AttributeUsage && !already_redefined.contains(&symbol.qualified_name))
+        .map(|symbol| CompletionItem {
+            label: symbol.name().to_string(),
+            kind: Some(completion_kind(symbol.kind)),
+            detail: Some(symbol.qualified_name.to_string()),
+            deprecated: Some(workspace.is_deprecated(&symbol.qualified_name)),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Combines candidate completions gathered from one or more of this
+/// module's `*_completions` functions into one deduplicated,
+/// relevance-ordered list — the step a caller takes before handing the
+/// list to the client.
+///
+/// Dedups by `(label, kind)`, keeping the first occurrence (so a name
+/// reachable two ways, e.g. a local definition that also happens to share
+/// a stdlib primitive's name, is only offered once — list the most
+/// relevant source first). Sets `sort_text` so the client orders direct
+/// members of `scope` above same-package symbols, above other
+/// already-resolved workspace symbols, above stdlib primitives, with an
+/// exact (case-sensitive) prefix match on `prefix` ranked above a
+/// non-matching item within its tier. Keywords (no `detail`, so their
+/// origin can't be classified) rank just below direct members, ahead of
+/// every other symbol tier; snippets carry a `detail` that names no loaded
+/// symbol, so they rank in the last tier alongside stdlib primitives.
+pub fn finalize_completions(workspace: &Workspace, scope: &QualifiedName, prefix: &str, items: Vec<CompletionItem>) -> Vec<CompletionItem> {
+    use std::collections::HashSet;
+
+    let mut seen = HashSet::new();
+    let mut items: Vec<CompletionItem> = items.into_iter().filter(|item| seen.insert((item.label.clone(), item.kind))).collect();
+
+    for item in &mut items {
+        let tier = relevance_tier(workspace, scope, item);
+        let exact: u8 = if item.label.starts_with(prefix) { 0 } else { 1 };
+        item.sort_text = Some(format!("{tier}{exact}{}", item.label));
+    }
+    items.sort_by(|a, b| a.sort_text.cmp(&b.sort_text));
+    items
+}
+
+/// Relevance tier for [`finalize_completions`]'s sort, lowest (most
+/// relevant) first: a direct member of `scope`, an item with no `detail`
+/// to classify by (a keyword), a symbol sharing `scope`'s top-level
+/// package, any other already-resolved workspace symbol, then anything
+/// else (a stdlib primitive, or a snippet whose `detail` names no loaded
+/// symbol).
+fn relevance_tier(workspace: &Workspace, scope: &QualifiedName, item: &CompletionItem) -> u8 {
+    let Some(detail) = &item.detail else { return 1 };
+    let name = QualifiedName::new(detail.as_str());
+
+    if name.parent().as_ref() == Some(scope) {
+        return 0;
+    }
+    match workspace.symbol_by_qualified_name(&name) {
+        Some(symbol) if !workspace.is_library_file(&symbol.file) => {
+            let scope_package = scope.to_string().split("::").next();
+            let name_package = detail.split("::").next();
+            if scope_package == name_package {
+                2
+            } else {
+                3
+            }
+        }
+        _ => 4,
+    }
+}
+
+/// Completion after a specialization operator (`:>`, `specializes`, or
+/// `subsets`) on a definition, e.g. `part def X :> ` — offers definitions
+/// of the same kind as the one being specialized (`enclosing_kind`), since
+/// SysML only allows specializing a compatible kind, plus the stdlib
+/// primitive types when `enclosing_kind` is
+/// [`PartDefinition`](syster_base::semantic::SymbolKind::PartDefinition),
+/// the same kind [`syster_base::semantic::stdlib::lookup_primitive`]
+/// resolves them to.
+pub fn specialization_completions(
+    workspace: &Workspace,
+    enclosing_kind: syster_base::semantic::SymbolKind,
+) -> Vec<CompletionItem> {
+    use syster_base::semantic::stdlib;
+    use syster_base::semantic::SymbolKind;
+
+    let to_item = |symbol: &syster_base::semantic::Symbol| CompletionItem {
+        label: symbol.qualified_name.to_string(),
+        kind: Some(completion_kind(symbol.kind)),
+        detail: Some(symbol.qualified_name.to_string()),
+        deprecated: Some(workspace.is_deprecated(&symbol.qualified_name)),
+        ..Default::default()
+    };
+
+    let mut items: Vec<CompletionItem> = workspace.all_of_kind(enclosing_kind).into_iter().map(to_item).collect();
+    if enclosing_kind == SymbolKind::PartDefinition {
+        items.extend(stdlib::list_primitives().iter().map(to_item));
+    }
+    items
+}
+
+/// Completion after `import A::` — offers `A`'s importable members
+/// (namespaces and definitions, not instance-level usages, since those
+/// aren't navigable import targets) plus the `*`/`**` wildcard-import
+/// completions.
+pub fn import_path_completions(workspace: &Workspace, scope: &QualifiedName) -> Vec<CompletionItem> {
+    let mut items: Vec<CompletionItem> = workspace
+        .children_of(scope)
+        .into_iter()
+        .filter(|symbol| is_importable(symbol.kind))
+        .map(|symbol| CompletionItem {
+            label: symbol.name().to_string(),
+            kind: Some(completion_kind(symbol.kind)),
+            detail: Some(symbol.qualified_name.to_string()),
+            deprecated: Some(workspace.is_deprecated(&symbol.qualified_name)),
+            ..Default::default()
+        })
+        .collect();
+
+    items.push(CompletionItem {
+        label: "*".to_string(),
+        detail: Some("import every member of this namespace".to_string()),
+        kind: Some(CompletionItemKind::OPERATOR),
+        ..Default::default()
+    });
+    items.push(CompletionItem {
+        label: "**".to_string(),
+        detail: Some("import every member of this namespace, recursively".to_string()),
+        kind: Some(CompletionItemKind::OPERATOR),
+        ..Default::default()
+    });
+    items
+}
+
+/// Completion for a defined-but-not-visible-from-`scope` symbol: offers the
+/// bare name plus an `additional_text_edits` entry that inserts
+/// `import <Package>::*;` at the top of `scope`'s enclosing top-level
+/// package, so accepting the item both writes the name and makes it
+/// resolve. A symbol already reachable from `scope` — same top-level
+/// package, or already covered by an `import` edge recorded against that
+/// package — is never offered here; [`member_access_completions`] and
+/// friends already cover those.
+///
+/// The inserted line lands at the declaring package's own declaration
+/// span, the closest thing to "top of the enclosing package" this tree's
+/// symbol table can answer without retaining source text.
+pub fn missing_import_completions(workspace: &Workspace, scope: &QualifiedName) -> Vec<CompletionItem> {
+    use syster_base::semantic::RelationshipKind;
+
+    let Some(scope_package_name) = scope.to_string().split("::").next().map(QualifiedName::new) else { return Vec::new() };
+    let Some(scope_package) = workspace.symbol_by_qualified_name(&scope_package_name) else { return Vec::new() };
+
+    let already_imported: std::collections::HashSet<&QualifiedName> =
+        workspace.relationships.edges(RelationshipKind::Import, &scope_package_name).iter().collect();
+
+    workspace
+        .symbols()
+        .iter()
+        .filter(|symbol| is_importable(symbol.kind))
+        .filter_map(|symbol| {
+            let defining_package = symbol.qualified_name.to_string().split("::").next().map(QualifiedName::new)?;
+            if defining_package == scope_package_name {
+                return None;
+            }
+            if already_imported.contains(&defining_package) || already_imported.contains(&symbol.qualified_name) {
+                return None;
+            }
+
+            let insert_at = Range {
+                start: LspPosition { line: scope_package.range().start.line, character: 0 },
+                end: LspPosition { line: scope_package.range().start.line, character: 0 },
+            };
+            Some(CompletionItem {
+                label: symbol.name().to_string(),
+                kind: Some(completion_kind(symbol.kind)),
+                detail: Some(symbol.qualified_name.to_string()),
+                deprecated: Some(workspace.is_deprecated(&symbol.qualified_name)),
+                additional_text_edits: Some(vec![TextEdit { range: insert_at, new_text: format!("import {defining_package}::*;\n") }]),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+/// Whether `kind` names something that can appear after `::` in an
+/// `import` path — namespaces and definitions, not the instance-level
+/// usages nested inside them.
+fn is_importable(kind: syster_base::semantic::SymbolKind) -> bool {
+    use syster_base::semantic::SymbolKind::*;
+    matches!(
+        kind,
+        Package
+            | PartDefinition
+            | PortDefinition
+            | ActionDefinition
+            | EnumerationDefinition
+            | RequirementDefinition
+            | VariationDefinition
+            | StateDefinition
+            | Alias
+    )
+}
+
+fn completion_kind(kind: syster_base::semantic::SymbolKind) -> CompletionItemKind {
+    use syster_base::semantic::SymbolKind::*;
+    match kind {
+        Package => CompletionItemKind::MODULE,
+        PartDefinition | PortDefinition | ActionDefinition | EnumerationDefinition | RequirementDefinition
+        | VariationDefinition | StateDefinition | ConcernDefinition => CompletionItemKind::CLASS,
+        PartUsage | PortUsage | ActionUsage | Connection | Interface | RequirementUsage | VariantUsage | StateUsage | SnapshotUsage | TimesliceUsage | ViewUsage | ConcernUsage => {
+            CompletionItemKind::FIELD
+        }
+        AttributeUsage => CompletionItemKind::PROPERTY,
+        LoopVariable => CompletionItemKind::VARIABLE,
+        EnumeratedValue => CompletionItemKind::ENUM_MEMBER,
+        Alias => CompletionItemKind::REFERENCE,
+    }
+}
+
+/// Definition keywords — valid after `abstract `, which only modifies a
+/// definition in this grammar's prefix rules, never a bare usage.
+const DEFINITION_KEYWORDS: &[&str] =
+    &["part def", "attribute def", "port def", "action def", "enum def", "requirement def", "variation def", "state def"];
+
+/// Usage keywords — valid after a usage-only modifier prefix (`ref`,
+/// `readonly`, a direction keyword), never after `abstract`.
+const USAGE_KEYWORDS: &[&str] = &["part", "attribute", "port", "action", "state", "connection", "interface"];
+
+/// Modifier/direction prefixes offered at the start of a statement,
+/// themselves filtering what can follow (see [`keyword_completions`]).
+const MODIFIER_KEYWORDS: &[&str] = &["abstract", "ref", "readonly", "in", "out", "inout"];
+
+fn keyword_items(keywords: &[&str]) -> Vec<CompletionItem> {
+    keywords
+        .iter()
+        .map(|keyword| CompletionItem { label: keyword.to_string(), kind: Some(CompletionItemKind::KEYWORD), ..Default::default() })
+        .collect()
+}
+
+/// Keyword completion for the start of a statement, filtered by whatever
+/// modifier prefix has already been typed on the line (`prefix` is
+/// everything up to the cursor; this function trims the trailing spaces
+/// that separate a complete keyword from where the cursor sits).
+///
+/// `abstract ` only ever precedes a definition, so usage-only keywords
+/// aren't offered there, and vice versa for a usage-only modifier like
+/// `ref `/`readonly `/a direction keyword. With no recognized modifier
+/// prefix (including an empty one, i.e. the start of a statement), every
+/// keyword — definitions, usages, and the modifiers themselves — is
+/// offered.
+pub fn keyword_completions(prefix: &str) -> Vec<CompletionItem> {
+    let typed = prefix.trim_end_matches(' ');
+    match typed {
+        "abstract" => keyword_items(DEFINITION_KEYWORDS),
+        "ref" | "readonly" | "in" | "out" | "inout" => keyword_items(USAGE_KEYWORDS),
+        "" => keyword_items(&[MODIFIER_KEYWORDS, DEFINITION_KEYWORDS, USAGE_KEYWORDS].concat()),
+        _ => Vec::new(),
+    }
+}
+
+/// Snippet completion for a `connect a to b;` connector, offered wherever a
+/// connector usage can legally appear — inside a part/connection/interface
+/// body. Not offered elsewhere (e.g. at package scope), where a bare
+/// `connect` statement isn't valid SysML.
+pub fn connector_snippet_completions(workspace: &Workspace, scope: &QualifiedName) -> Vec<CompletionItem> {
+    use syster_base::semantic::SymbolKind::*;
+
+    let Some(scope_symbol) = workspace.symbol_by_qualified_name(scope) else { return Vec::new() };
+    if !matches!(scope_symbol.kind, PartDefinition | PartUsage | Connection | Interface) {
+        return Vec::new();
+    }
+
+    vec![CompletionItem {
+        label: "connect".to_string(),
+        kind: Some(CompletionItemKind::SNIPPET),
+        detail: Some("connector".to_string()),
+        insert_text: Some("connect ${1:a} to ${2:b};".to_string()),
+        insert_text_format: Some(InsertTextFormat::SNIPPET),
+        ..Default::default()
+    }]
+}
+
+/// Snippet completion for a `transition first S1 then S2;`, offered only
+/// inside a state body, where a bare `transition` statement is valid.
+pub fn transition_snippet_completions(workspace: &Workspace, scope: &QualifiedName) -> Vec<CompletionItem> {
+    use syster_base::semantic::SymbolKind::*;
+
+    let Some(scope_symbol) = workspace.symbol_by_qualified_name(scope) else { return Vec::new() };
+    if !matches!(scope_symbol.kind, StateDefinition | StateUsage) {
+        return Vec::new();
+    }
+
+    vec![CompletionItem {
+        label: "transition".to_string(),
+        kind: Some(CompletionItemKind::SNIPPET),
+        detail: Some("transition".to_string()),
+        insert_text: Some("transition first ${1:S1} then ${2:S2};".to_string()),
+        insert_text_format: Some(InsertTextFormat::SNIPPET),
+        ..Default::default()
+    }]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use syster_base::semantic::{Symbol, SymbolKind};
+    use syster_base::span::{Position, Span};
+
+    #[test]
+    fn member_access_completion_lists_direct_children_of_scope() {
+        let mut ws = Workspace::default();
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::wheels"), SymbolKind::PartUsage, file, span));
+
+        let items = member_access_completions(&ws, &QualifiedName::new("Vehicle"));
+
+        let labels: Vec<_> = items.iter().map(|i| i.label.as_str()).collect();
+        assert!(labels.contains(&"engine"));
+        assert!(labels.contains(&"wheels"));
+    }
+
+    #[test]
+    fn enum_value_completion_after_equals_on_an_enum_typed_feature_offers_its_values() {
+        use syster_base::semantic::RelationshipKind;
+
+        let mut ws = Workspace::default();
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Color"), SymbolKind::EnumerationDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Color::red"), SymbolKind::EnumeratedValue, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Color::green"), SymbolKind::EnumeratedValue, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::c"), SymbolKind::AttributeUsage, file, span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::c"), QualifiedName::new("Color"));
+
+        let items = enum_value_completions(&ws, &QualifiedName::new("Vehicle::c"));
+
+        let labels: Vec<_> = items.iter().map(|i| i.label.as_str()).collect();
+        assert!(labels.contains(&"Color::red"));
+        assert!(labels.contains(&"Color::green"));
+    }
+
+    #[test]
+    fn enum_value_completion_is_empty_for_a_feature_not_typed_by_an_enum() {
+        let mut ws = Workspace::default();
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, file, span));
+
+        assert!(enum_value_completions(&ws, &QualifiedName::new("Vehicle::engine")).is_empty());
+    }
+
+    #[test]
+    fn specialization_completion_after_a_part_def_offers_other_part_defs_but_not_requirement_defs() {
+        let mut ws = Workspace::default();
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::PartDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Engine"), SymbolKind::PartDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("SafetyRequirement"), SymbolKind::RequirementDefinition, file, span));
+
+        let items = specialization_completions(&ws, SymbolKind::PartDefinition);
+
+        let labels: Vec<_> = items.iter().map(|i| i.label.as_str()).collect();
+        assert!(labels.contains(&"Vehicle"));
+        assert!(labels.contains(&"Engine"));
+        assert!(labels.contains(&"Integer"), "stdlib primitives are also compatible part def bases");
+        assert!(!labels.contains(&"SafetyRequirement"), "a part def can't specialize a requirement def");
+    }
+
+    #[test]
+    fn redefinition_completion_offers_inherited_attributes_not_yet_redefined() {
+        use syster_base::semantic::RelationshipKind;
+
+        let mut ws = Workspace::default();
+        let file = PathBuf::from("Base.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Base"), SymbolKind::AttributeUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Base::level"), SymbolKind::AttributeUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Base::name"), SymbolKind::AttributeUsage, file.clone(), span));
+
+        ws.insert_symbol(Symbol::new(QualifiedName::new("MyMeta"), SymbolKind::AttributeUsage, file.clone(), span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("MyMeta"), QualifiedName::new("Base"));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("MyMeta::level"), SymbolKind::AttributeUsage, file, span));
+        ws.relationships.add_edge(RelationshipKind::Redefinition, QualifiedName::new("MyMeta::level"), QualifiedName::new("Base::level"));
+
+        let items = redefinition_completions(&ws, &QualifiedName::new("MyMeta"));
+
+        let labels: Vec<_> = items.iter().map(|i| i.label.as_str()).collect();
+        assert!(labels.contains(&"name"), "an inherited attribute not yet redefined should be offered");
+        assert!(!labels.contains(&"level"), "an attribute MyMeta already redefines shouldn't be offered again");
+    }
+
+    #[test]
+    fn finalize_completions_dedups_by_label_and_kind_and_ranks_locals_above_identically_named_stdlib() {
+        let mut ws = Workspace::default();
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::PartDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Integer"), SymbolKind::PartDefinition, file, span));
+
+        let items = specialization_completions(&ws, SymbolKind::PartDefinition);
+        let labels_before: Vec<_> = items.iter().map(|i| i.label.clone()).collect();
+        assert_eq!(labels_before.iter().filter(|l| *l == "Integer").count(), 2, "a local and a stdlib 'Integer' both appear before finalizing");
+
+        let finalized = finalize_completions(&ws, &QualifiedName::new("Other"), "", items);
+
+        let integers: Vec<_> = finalized.iter().filter(|i| i.label == "Integer").collect();
+        assert_eq!(integers.len(), 1, "the duplicate 'Integer' label should be deduplicated");
+
+        let integer_index = finalized.iter().position(|i| i.label == "Integer").unwrap();
+        let real_index = finalized.iter().position(|i| i.label == "Real").unwrap();
+        assert!(integer_index < real_index, "the surviving 'Integer' is the local one, so it sorts above a stdlib-only primitive");
+    }
+
+    #[test]
+    fn finalize_completions_ranks_an_exact_prefix_match_above_a_non_matching_item_in_the_same_tier() {
+        let mut ws = Workspace::default();
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::PartDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::wheels"), SymbolKind::PartUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::windshield"), SymbolKind::PartUsage, file, span));
+
+        let items = member_access_completions(&ws, &QualifiedName::new("Vehicle"));
+        let finalized = finalize_completions(&ws, &QualifiedName::new("Vehicle"), "wind", items);
+
+        assert_eq!(finalized[0].label, "windshield");
+    }
+
+    #[test]
+    fn import_path_completion_offers_package_members_and_wildcard_imports() {
+        let mut ws = Workspace::default();
+        let file = PathBuf::from("ISQ.kerml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("ISQ"), SymbolKind::Package, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("ISQ::LengthValue"), SymbolKind::AttributeUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("ISQ::MassUnit"), SymbolKind::PartDefinition, file, span));
+
+        let items = import_path_completions(&ws, &QualifiedName::new("ISQ"));
+
+        let labels: Vec<_> = items.iter().map(|i| i.label.as_str()).collect();
+        assert!(labels.contains(&"MassUnit"), "definitions should be offered as import targets");
+        assert!(!labels.contains(&"LengthValue"), "instance-level usages aren't navigable import targets");
+        assert!(labels.contains(&"*"));
+        assert!(labels.contains(&"**"));
+    }
+
+    #[test]
+    fn missing_import_completion_adds_an_import_edit_for_an_out_of_scope_definition() {
+        let mut ws = Workspace::default();
+        let isq_span = Span::new(Position::new(0, 0), Position::new(0, 3));
+        let isq_file = PathBuf::from("ISQ.kerml");
+        ws.insert_symbol(Symbol::new(QualifiedName::new("ISQ"), SymbolKind::Package, isq_file.clone(), isq_span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("ISQ::MassUnit"), SymbolKind::PartDefinition, isq_file, isq_span));
+
+        let vehicle_span = Span::new(Position::new(5, 0), Position::new(5, 7));
+        let vehicle_file = PathBuf::from("Vehicle.sysml");
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::Package, vehicle_file.clone(), vehicle_span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::Car"), SymbolKind::PartDefinition, vehicle_file, vehicle_span));
+
+        let items = missing_import_completions(&ws, &QualifiedName::new("Vehicle::Car"));
+
+        let item = items.iter().find(|i| i.label == "MassUnit").expect("MassUnit should be offered with an import edit");
+        assert!(!items.iter().any(|i| i.label == "Car"), "a definition already in scope shouldn't be offered here");
+
+        let edits = item.additional_text_edits.as_ref().expect("an import-inserting edit");
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].new_text, "import ISQ::*;\n");
+        assert_eq!(edits[0].range.start.line, vehicle_span.start.line, "the import is inserted at the enclosing package's declaration");
+    }
+
+    #[test]
+    fn missing_import_completion_skips_a_package_already_imported() {
+        use syster_base::semantic::RelationshipKind;
+
+        let mut ws = Workspace::default();
+        let isq_span = Span::new(Position::new(0, 0), Position::new(0, 3));
+        let isq_file = PathBuf::from("ISQ.kerml");
+        ws.insert_symbol(Symbol::new(QualifiedName::new("ISQ"), SymbolKind::Package, isq_file.clone(), isq_span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("ISQ::MassUnit"), SymbolKind::PartDefinition, isq_file, isq_span));
+
+        let vehicle_span = Span::new(Position::new(5, 0), Position::new(5, 7));
+        let vehicle_file = PathBuf::from("Vehicle.sysml");
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::Package, vehicle_file, vehicle_span));
+        ws.relationships.add_edge(RelationshipKind::Import, QualifiedName::new("Vehicle"), QualifiedName::new("ISQ"));
+
+        assert!(missing_import_completions(&ws, &QualifiedName::new("Vehicle")).is_empty());
+    }
+
+    #[test]
+    fn initial_completion_items_carry_no_documentation() {
+        let mut ws = Workspace::default();
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, file, span));
+        ws.doc_comments.set(QualifiedName::new("Vehicle::engine"), "The primary power source.");
+
+        let items = member_access_completions(&ws, &QualifiedName::new("Vehicle"));
+
+        assert!(items[0].documentation.is_none());
+    }
+
+    #[test]
+    fn resolving_a_completion_item_returns_its_doc_comment() {
+        let mut ws = Workspace::default();
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, file, span));
+        ws.doc_comments.set(QualifiedName::new("Vehicle::engine"), "The primary power source.");
+
+        let item = member_access_completions(&ws, &QualifiedName::new("Vehicle")).remove(0);
+        let resolved = resolve_completion_item(&ws, item);
+
+        match resolved.documentation {
+            Some(Documentation::MarkupContent(content)) => assert_eq!(content.value, "The primary power source."),
+            other => panic!("expected markdown documentation, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn transition_snippet_appears_inside_a_state_body_but_not_at_top_level() {
+        let mut ws = Workspace::default();
+        let file = PathBuf::from("Light.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Light"), SymbolKind::Package, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Light::On"), SymbolKind::StateUsage, file, span));
+
+        let inside_state = transition_snippet_completions(&ws, &QualifiedName::new("Light::On"));
+        assert_eq!(inside_state.len(), 1);
+        assert_eq!(inside_state[0].insert_text_format, Some(InsertTextFormat::SNIPPET));
+        assert_eq!(inside_state[0].insert_text.as_deref(), Some("transition first ${1:S1} then ${2:S2};"));
+
+        assert!(transition_snippet_completions(&ws, &QualifiedName::new("Light")).is_empty());
+    }
+
+    #[test]
+    fn keyword_completion_after_abstract_offers_only_definition_keywords() {
+        let items = keyword_completions("abstract ");
+
+        let labels: Vec<_> = items.iter().map(|i| i.label.as_str()).collect();
+        assert!(labels.contains(&"part def"));
+        assert!(labels.contains(&"attribute def"));
+        assert!(!labels.contains(&"part"), "usage-only keywords shouldn't follow `abstract`");
+        assert!(!labels.contains(&"abstract"), "a modifier shouldn't repeat itself");
+    }
+
+    #[test]
+    fn keyword_completion_after_a_usage_only_modifier_offers_only_usage_keywords() {
+        let items = keyword_completions("ref ");
+
+        let labels: Vec<_> = items.iter().map(|i| i.label.as_str()).collect();
+        assert!(labels.contains(&"part"));
+        assert!(!labels.contains(&"part def"), "definitions shouldn't follow a usage-only modifier");
+    }
+
+    #[test]
+    fn keyword_completion_at_the_start_of_a_statement_offers_every_keyword() {
+        let items = keyword_completions("");
+
+        let labels: Vec<_> = items.iter().map(|i| i.label.as_str()).collect();
+        assert!(labels.contains(&"abstract"));
+        assert!(labels.contains(&"part def"));
+        assert!(labels.contains(&"part"));
+    }
+
+    #[test]
+    fn connector_snippet_appears_inside_a_part_body_but_not_inside_a_state_body() {
+        let mut ws = Workspace::default();
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::PartDefinition, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::On"), SymbolKind::StateUsage, file, span));
+
+        assert_eq!(connector_snippet_completions(&ws, &QualifiedName::new("Vehicle")).len(), 1);
+        assert!(connector_snippet_completions(&ws, &QualifiedName::new("Vehicle::On")).is_empty());
+    }
+}
@@ -0,0 +1,139 @@
+//! `textDocument/diagnostic` — the LSP 3.17 pull model, where the client
+//! asks for a document's diagnostics on demand instead of the server
+//! pushing them via `textDocument/publishDiagnostics`.
+
+use lsp_types::{
+    Diagnostic as LspDiagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, Location, Position as LspPosition, Range, Url,
+};
+use syster_base::diagnostics::{self, Severity};
+use syster_base::Workspace;
+
+/// Computes the diagnostics for a single file on demand, for use by the
+/// `textDocument/diagnostic` pull request. Unlike the push model, this
+/// never needs to track "what did we last publish" — the client asks
+/// again whenever it wants a fresh result.
+///
+/// Runs the same gating passes as `syster validate`
+/// ([`diagnostics::run_validation_suite`]) rather than just
+/// [`diagnostics::check_typing`], so this doesn't systematically
+/// under-report compared to the CLI on the same file. Parse errors are
+/// kept on their own `to_lsp_parse_diagnostic` path (for the
+/// `syster-parser` source clients key off of) and excluded from the
+/// suite's own [`diagnostics::PARSE_ERROR`] findings to avoid reporting
+/// them twice.
+pub fn pull_diagnostics(workspace: &Workspace, file: &std::path::Path) -> Vec<LspDiagnostic> {
+    let semantic = diagnostics::run_validation_suite(workspace)
+        .diagnostics
+        .into_iter()
+        .filter(|d| d.file == file && d.code != diagnostics::PARSE_ERROR)
+        .map(to_lsp_diagnostic);
+    let parse = workspace.parse_errors(file).iter().cloned().map(to_lsp_parse_diagnostic);
+    parse.chain(semantic).collect()
+}
+
+fn to_lsp_parse_diagnostic(error: syster_base::parser::RecoveredError) -> LspDiagnostic {
+    LspDiagnostic {
+        range: Range {
+            start: LspPosition { line: error.span.start.line, character: error.span.start.column },
+            end: LspPosition { line: error.span.end.line, character: error.span.end.column },
+        },
+        severity: Some(DiagnosticSeverity::ERROR),
+        source: Some("syster-parser".to_string()),
+        message: error.message,
+        ..Default::default()
+    }
+}
+
+fn to_lsp_diagnostic(d: diagnostics::Diagnostic) -> LspDiagnostic {
+    let related_information = (!d.related.is_empty()).then(|| {
+        d.related
+            .into_iter()
+            .filter_map(|r| {
+                Some(DiagnosticRelatedInformation {
+                    location: Location {
+                        uri: Url::from_file_path(&r.file).ok()?,
+                        range: Range {
+                            start: LspPosition { line: r.span.start.line, character: r.span.start.column },
+                            end: LspPosition { line: r.span.end.line, character: r.span.end.column },
+                        },
+                    },
+                    message: r.message,
+                })
+            })
+            .collect()
+    });
+
+    LspDiagnostic {
+        range: Range {
+            start: LspPosition { line: d.span.start.line, character: d.span.start.column },
+            end: LspPosition { line: d.span.end.line, character: d.span.end.column },
+        },
+        severity: Some(to_lsp_severity(d.severity)),
+        code: Some(lsp_types::NumberOrString::String(d.code.to_string())),
+        message: d.message,
+        related_information,
+        ..Default::default()
+    }
+}
+
+fn to_lsp_severity(severity: Severity) -> DiagnosticSeverity {
+    match severity {
+        Severity::Error => DiagnosticSeverity::ERROR,
+        Severity::Warning => DiagnosticSeverity::WARNING,
+        Severity::Information => DiagnosticSeverity::INFORMATION,
+        Severity::Hint => DiagnosticSeverity::HINT,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use syster_base::semantic::{QualifiedName, RelationshipKind, Symbol, SymbolKind};
+    use syster_base::span::{Position, Span};
+
+    #[test]
+    fn pull_diagnostics_includes_recovered_parse_errors() {
+        let file = PathBuf::from("Broken.sysml");
+        let mut ws = Workspace::default();
+        ws.set_parse_errors(
+            file.clone(),
+            vec![syster_base::parser::RecoveredError::new("expected ';'", Span::new(Position::new(0, 5), Position::new(0, 6)))],
+        );
+
+        let diagnostics = pull_diagnostics(&ws, &file);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].source.as_deref(), Some("syster-parser"));
+    }
+
+    #[test]
+    fn pull_diagnostics_only_returns_the_requested_file() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let other = PathBuf::from("Other.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, file.clone(), span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::p"), SymbolKind::PartUsage, file.clone(), span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::p"), QualifiedName::new("Vehicle::engine"));
+
+        assert_eq!(pull_diagnostics(&ws, &file).len(), 1);
+        assert_eq!(pull_diagnostics(&ws, &other).len(), 0);
+    }
+
+    #[test]
+    fn pull_diagnostics_surfaces_checks_beyond_typing_such_as_unresolved_references() {
+        let file = PathBuf::from("Vehicle.sysml");
+        let span = Span::new(Position::new(0, 0), Position::new(0, 1));
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::p"), SymbolKind::PartUsage, file.clone(), span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::p"), QualifiedName::new("MissingType"));
+
+        let diagnostics = pull_diagnostics(&ws, &file);
+
+        assert!(
+            diagnostics.iter().any(|d| d.code == Some(lsp_types::NumberOrString::String(syster_base::diagnostics::UNRESOLVED_REFERENCE.to_string()))),
+            "expected an unresolved-reference finding, got {diagnostics:?}"
+        );
+    }
+}
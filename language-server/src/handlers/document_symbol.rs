@@ -0,0 +1,156 @@
+//! `textDocument/documentSymbol`: a file's declaration outline as either
+//! nested [`DocumentSymbol`]s (the modern, hierarchical shape) or a flat
+//! [`SymbolInformation`] list with `container_name` set to the parent's
+//! qualified name, for clients that don't advertise
+//! `DocumentSymbolClientCapabilities::hierarchical_document_symbol_support`
+//! during `initialize` (see
+//! [`crate::server::Backend::configure_document_symbols`]).
+
+use std::path::Path;
+
+use lsp_types::{DocumentSymbol, DocumentSymbolResponse, Location, Position as LspPosition, Range, SymbolInformation, SymbolKind as LspSymbolKind, Url};
+use syster_base::semantic::SymbolKind;
+use syster_base::span::Span;
+use syster_base::{DocumentSymbolOptions, OutlineEntry, Workspace};
+
+fn to_range(span: Span) -> Range {
+    Range { start: LspPosition { line: span.start.line, character: span.start.column }, end: LspPosition { line: span.end.line, character: span.end.column } }
+}
+
+fn lsp_symbol_kind(kind: SymbolKind) -> LspSymbolKind {
+    use SymbolKind::*;
+    match kind {
+        Package => LspSymbolKind::PACKAGE,
+        PartDefinition | PortDefinition | ActionDefinition | EnumerationDefinition | RequirementDefinition
+        | VariationDefinition | StateDefinition | ConcernDefinition => LspSymbolKind::CLASS,
+        PartUsage | PortUsage | ActionUsage | Connection | Interface | RequirementUsage | VariantUsage | StateUsage | SnapshotUsage | TimesliceUsage | ViewUsage | ConcernUsage => {
+            LspSymbolKind::FIELD
+        }
+        AttributeUsage => LspSymbolKind::PROPERTY,
+        LoopVariable => LspSymbolKind::VARIABLE,
+        EnumeratedValue => LspSymbolKind::ENUM_MEMBER,
+        Alias => LspSymbolKind::NAMESPACE,
+    }
+}
+
+/// The root entries of `file`'s own outline: every symbol declared there
+/// with no enclosing scope, each expanded into its members via
+/// [`Workspace::document_symbols`]. Since that recursion goes through
+/// [`Workspace::children_of`], a root package "reopened" across several
+/// files reports the union of every file's members under it, not just
+/// this one's — the same merge [`Workspace::hover`] already relies on for
+/// a package's member count.
+fn outline_for_file(workspace: &Workspace, file: &Path, options: &DocumentSymbolOptions) -> Vec<OutlineEntry> {
+    workspace
+        .symbols()
+        .iter()
+        .filter(|symbol| symbol.file == file && symbol.qualified_name.parent().is_none())
+        .map(|symbol| OutlineEntry {
+            name: symbol.name().to_string(),
+            qualified_name: symbol.qualified_name.clone(),
+            kind: symbol.kind,
+            is_reference: false,
+            children: workspace.document_symbols(&symbol.qualified_name, options),
+        })
+        .collect()
+}
+
+fn to_document_symbol(workspace: &Workspace, entry: &OutlineEntry) -> Option<DocumentSymbol> {
+    let range = to_range(workspace.symbol_by_qualified_name(&entry.qualified_name)?.range());
+    Some(DocumentSymbol {
+        name: entry.name.clone(),
+        detail: Some(entry.qualified_name.to_string()),
+        kind: lsp_symbol_kind(entry.kind),
+        range,
+        selection_range: range,
+        children: Some(entry.children.iter().filter_map(|child| to_document_symbol(workspace, child)).collect()),
+        ..Default::default()
+    })
+}
+
+/// Flattens `entry` and its descendants into `out`, each carrying
+/// `container_name` set to its immediate parent's qualified name (`None`
+/// for a root entry) — the shape `SymbolInformation`-only clients expect
+/// in place of real nesting.
+fn flatten_into(workspace: &Workspace, entry: &OutlineEntry, container_name: Option<&str>, fallback_uri: &Url, out: &mut Vec<SymbolInformation>) {
+    let Some(symbol) = workspace.symbol_by_qualified_name(&entry.qualified_name) else { return };
+    let uri = Url::from_file_path(&symbol.file).unwrap_or_else(|_| fallback_uri.clone());
+    out.push(SymbolInformation {
+        name: entry.name.clone(),
+        kind: lsp_symbol_kind(entry.kind),
+        location: Location { uri, range: to_range(symbol.range()) },
+        container_name: container_name.map(str::to_string),
+        ..Default::default()
+    });
+    for child in &entry.children {
+        flatten_into(workspace, child, Some(entry.qualified_name.as_str()), fallback_uri, out);
+    }
+}
+
+/// `textDocument/documentSymbol` for `file`: nested when `hierarchical` is
+/// set (the default a client gets unless told otherwise), flat
+/// `SymbolInformation` otherwise.
+pub fn get_document_symbols(workspace: &Workspace, uri: &Url, file: &Path, options: &DocumentSymbolOptions, hierarchical: bool) -> DocumentSymbolResponse {
+    let outline = outline_for_file(workspace, file, options);
+    if hierarchical {
+        DocumentSymbolResponse::Nested(outline.iter().filter_map(|entry| to_document_symbol(workspace, entry)).collect())
+    } else {
+        let mut flat = Vec::new();
+        for entry in &outline {
+            flatten_into(workspace, entry, None, uri, &mut flat);
+        }
+        DocumentSymbolResponse::Flat(flat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use syster_base::semantic::{QualifiedName, Symbol};
+    use syster_base::span::Position;
+
+    fn sample_workspace() -> (Workspace, PathBuf) {
+        let file = PathBuf::from("/tmp/Vehicle.sysml");
+        let mut ws = Workspace::default();
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::Package, file.clone(), Span::new(Position::new(0, 8), Position::new(0, 15))));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::Engine"), SymbolKind::PartDefinition, file.clone(), Span::new(Position::new(1, 9), Position::new(1, 15))));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::Engine::power"), SymbolKind::AttributeUsage, file.clone(), Span::new(Position::new(2, 4), Position::new(2, 9))));
+        (ws, file)
+    }
+
+    #[test]
+    fn hierarchical_response_nests_members_under_their_declaring_symbol() {
+        let (ws, file) = sample_workspace();
+        let uri = Url::from_file_path(&file).unwrap();
+
+        let response = get_document_symbols(&ws, &uri, &file, &DocumentSymbolOptions::default(), true);
+
+        let DocumentSymbolResponse::Nested(roots) = response else { panic!("expected a nested response") };
+        assert_eq!(roots.len(), 1);
+        assert_eq!(roots[0].name, "Vehicle");
+        let engine = &roots[0].children.as_ref().unwrap()[0];
+        assert_eq!(engine.name, "Engine");
+        assert_eq!(engine.children.as_ref().unwrap()[0].name, "power");
+    }
+
+    #[test]
+    fn flat_response_carries_the_immediate_parent_s_qualified_name_as_container() {
+        let (ws, file) = sample_workspace();
+        let uri = Url::from_file_path(&file).unwrap();
+
+        let response = get_document_symbols(&ws, &uri, &file, &DocumentSymbolOptions::default(), false);
+
+        let DocumentSymbolResponse::Flat(symbols) = response else { panic!("expected a flat response") };
+        assert_eq!(symbols.len(), 3);
+
+        let vehicle = symbols.iter().find(|s| s.name == "Vehicle").unwrap();
+        assert_eq!(vehicle.container_name, None);
+
+        let engine = symbols.iter().find(|s| s.name == "Engine").unwrap();
+        assert_eq!(engine.container_name, Some("Vehicle".to_string()));
+
+        let power = symbols.iter().find(|s| s.name == "power").unwrap();
+        assert_eq!(power.container_name, Some("Vehicle::Engine".to_string()));
+    }
+}
@@ -0,0 +1,159 @@
+//! `textDocument/foldingRange` and `textDocument/selectionRange`, dispatched
+//! by the document's language so a `.sysml` file never routes through the
+//! KerML-specific extractors (and vice versa, once SysML folding lands).
+
+use lsp_types::{FoldingRange, FoldingRangeKind, Position as LspPosition, Range, SelectionRange};
+use syster_base::ast::{extract_kerml_folding_ranges, find_kerml_selection_spans, AstNode};
+use syster_base::span::{Position, Span};
+pub use syster_base::syntax::{language_for_path, Language};
+
+/// Folding ranges for `ast`. Only KerML is wired up today; SysML's
+/// extractor follows the same shape once its grammar lands.
+pub fn get_folding_ranges(language: Language, ast: &AstNode) -> Vec<FoldingRange> {
+    match language {
+        Language::KerML => extract_kerml_folding_ranges(ast).into_iter().map(to_folding_range).collect(),
+        Language::SysML => Vec::new(),
+    }
+}
+
+/// Selection ranges for `position`, nested from innermost span outward, one
+/// entry per requested position per the `textDocument/selectionRange` shape.
+pub fn get_selection_ranges(language: Language, ast: &AstNode, position: Position) -> Option<SelectionRange> {
+    let spans = match language {
+        Language::KerML => find_kerml_selection_spans(ast, position),
+        Language::SysML => Vec::new(),
+    };
+    build_selection_chain(&spans)
+}
+
+fn build_selection_chain(spans: &[Span]) -> Option<SelectionRange> {
+    let (first, rest) = spans.split_first()?;
+    let parent = build_selection_chain(rest).map(Box::new);
+    Some(SelectionRange { range: to_range(*first), parent })
+}
+
+/// Adjusts raw folding ranges for a client's capabilities: strips
+/// `start_character`/`end_character` when it only supports whole-line
+/// folding (`lineFoldingOnly`, read once during `initialize` — see
+/// [`crate::server::Backend::configure_folding`]), and truncates to
+/// `max_ranges` entries so a huge file can't overwhelm a client that
+/// didn't ask for everything.
+pub fn apply_folding_range_limits(mut ranges: Vec<FoldingRange>, line_folding_only: bool, max_ranges: Option<usize>) -> Vec<FoldingRange> {
+    if line_folding_only {
+        for range in &mut ranges {
+            range.start_character = None;
+            range.end_character = None;
+        }
+    }
+    if let Some(max_ranges) = max_ranges {
+        ranges.truncate(max_ranges);
+    }
+    ranges
+}
+
+fn to_folding_range(span: Span) -> FoldingRange {
+    FoldingRange {
+        start_line: span.start.line,
+        start_character: Some(span.start.column),
+        end_line: span.end.line,
+        end_character: Some(span.end.column),
+        kind: Some(FoldingRangeKind::Region),
+        collapsed_text: None,
+    }
+}
+
+fn to_range(span: Span) -> Range {
+    Range {
+        start: LspPosition { line: span.start.line, character: span.start.column },
+        end: LspPosition { line: span.end.line, character: span.end.column },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+    use syster_base::semantic::SymbolKind;
+
+    fn span(start: (u32, u32), end: (u32, u32)) -> Span {
+        Span::new(Position::new(start.0, start.1), Position::new(end.0, end.1))
+    }
+
+    fn sample_kerml_tree() -> AstNode {
+        AstNode::new("Vehicle", SymbolKind::PartDefinition, span((0, 0), (5, 1))).with_children(vec![AstNode::new(
+            "engine",
+            SymbolKind::PartUsage,
+            span((1, 4), (3, 5)),
+        )
+        .with_children(vec![AstNode::new(
+            "power",
+            SymbolKind::AttributeUsage,
+            span((2, 8), (2, 20)),
+        )])])
+    }
+
+    #[test]
+    fn kerml_document_returns_folding_ranges_for_every_block() {
+        let ranges = get_folding_ranges(Language::KerML, &sample_kerml_tree());
+        assert_eq!(ranges.len(), 2);
+        assert_eq!(ranges[0].start_line, 0);
+        assert_eq!(ranges[1].start_line, 1);
+    }
+
+    #[test]
+    fn kerml_document_returns_a_nested_selection_chain() {
+        let selection = get_selection_ranges(Language::KerML, &sample_kerml_tree(), Position::new(2, 10)).unwrap();
+        assert_eq!(selection.range.start.line, 2);
+        let parent = selection.parent.unwrap();
+        assert_eq!(parent.range.start.line, 1);
+        let grandparent = parent.parent.unwrap();
+        assert_eq!(grandparent.range.start.line, 0);
+        assert!(grandparent.parent.is_none());
+    }
+
+    #[test]
+    fn opening_a_kerml_file_dispatches_folding_and_selection_together() {
+        let path = Path::new("Vehicle.kerml");
+        let language = language_for_path(path).unwrap();
+        let ast = sample_kerml_tree();
+
+        let folding = get_folding_ranges(language, &ast);
+        let selection = get_selection_ranges(language, &ast, Position::new(2, 10)).unwrap();
+
+        assert_eq!(folding.len(), 2);
+        assert_eq!(selection.range.start.line, 2);
+    }
+
+    #[test]
+    fn sysml_document_yields_no_ranges_until_its_extractor_lands() {
+        assert!(get_folding_ranges(Language::SysML, &sample_kerml_tree()).is_empty());
+        assert!(get_selection_ranges(Language::SysML, &sample_kerml_tree(), Position::new(2, 10)).is_none());
+    }
+
+    #[test]
+    fn line_folding_only_strips_character_offsets_but_keeps_the_lines() {
+        let ranges = get_folding_ranges(Language::KerML, &sample_kerml_tree());
+        assert!(ranges.iter().any(|r| r.start_character.is_some()));
+
+        let limited = apply_folding_range_limits(ranges.clone(), true, None);
+
+        assert_eq!(limited.len(), ranges.len());
+        for (limited, original) in limited.iter().zip(ranges.iter()) {
+            assert!(limited.start_character.is_none());
+            assert!(limited.end_character.is_none());
+            assert_eq!(limited.start_line, original.start_line);
+            assert_eq!(limited.end_line, original.end_line);
+        }
+    }
+
+    #[test]
+    fn max_ranges_truncates_without_touching_character_offsets() {
+        let ranges = get_folding_ranges(Language::KerML, &sample_kerml_tree());
+        assert_eq!(ranges.len(), 2);
+
+        let limited = apply_folding_range_limits(ranges, false, Some(1));
+
+        assert_eq!(limited.len(), 1);
+        assert!(limited[0].start_character.is_some());
+    }
+}
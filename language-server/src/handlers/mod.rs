@@ -0,0 +1,10 @@
+//! LSP request handlers, one module per method.
+
+pub mod completion;
+pub mod diagnostics;
+pub mod document_symbol;
+pub mod folding;
+pub mod on_type_formatting;
+pub mod rename;
+pub mod rename_files;
+pub mod semantic_tokens;
@@ -0,0 +1,76 @@
+//! `textDocument/onTypeFormatting` — reindent and auto-close braces as the
+//! user types, rather than waiting for an explicit format request.
+
+use lsp_types::{Position as LspPosition, Range, TextEdit};
+use syster_base::format::{FormatOptions, IndentUnit};
+
+/// Characters that trigger on-type formatting; registered verbatim as the
+/// server's `DocumentOnTypeFormattingOptions::first_trigger_character` /
+/// `more_trigger_character`.
+pub const FIRST_TRIGGER_CHARACTER: &str = "}";
+pub const MORE_TRIGGER_CHARACTERS: &[&str] = &["\n", "{"];
+
+/// Computes the edits for one on-type formatting event.
+///
+/// - Typing `{` at end of line inserts a matching `}` on the next line,
+///   indented one level deeper, with the cursor left between them.
+/// - Typing `}` dedents its line by one indent level (in spaces) from the
+///   line's current indentation; the opener's line is not consulted.
+/// - Typing newline produces no edits.
+pub fn on_type_format(line_text: &str, line: u32, trigger: &str, options: &FormatOptions) -> Vec<TextEdit> {
+    let indent = render_indent(1, options);
+    match trigger {
+        "{" if line_text.trim_end().ends_with('{') => {
+            let base: String = line_text.chars().take_while(|c| c.is_whitespace()).collect();
+            vec![TextEdit {
+                range: Range {
+                    start: LspPosition { line, character: line_text.len() as u32 },
+                    end: LspPosition { line, character: line_text.len() as u32 },
+                },
+                new_text: format!("\n{base}{indent}\n{base}}}"),
+            }]
+        }
+        "}" => {
+            let current_indent = leading_whitespace_len(line_text);
+            let target_indent = current_indent.saturating_sub(options.indent_width as u32);
+            vec![TextEdit {
+                range: Range {
+                    start: LspPosition { line, character: 0 },
+                    end: LspPosition { line, character: current_indent },
+                },
+                new_text: " ".repeat(target_indent as usize),
+            }]
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn leading_whitespace_len(line: &str) -> u32 {
+    line.chars().take_while(|c| *c == ' ').count() as u32
+}
+
+fn render_indent(level: usize, options: &FormatOptions) -> String {
+    match options.indent_unit {
+        IndentUnit::Spaces => " ".repeat(level * options.indent_width),
+        IndentUnit::Tabs => "\t".repeat(level),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opening_brace_inserts_a_matching_close_on_next_line() {
+        let edits = on_type_format("part def Vehicle {", 0, "{", &FormatOptions::default());
+        assert_eq!(edits.len(), 1);
+        assert!(edits[0].new_text.contains('\n'));
+    }
+
+    #[test]
+    fn closing_brace_dedents_to_match_its_opener() {
+        let edits = on_type_format("        }", 4, "}", &FormatOptions::default());
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].new_text, "    ");
+    }
+}
@@ -0,0 +1,100 @@
+//! `textDocument/rename`/`textDocument/prepareRename`-adjacent machinery:
+//! the `WorkspaceEdit` a symbol rename would apply, and a locations-only
+//! preview of the same edit set for clients that want to show "N
+//! locations will change" before committing. Distinct from
+//! [`crate::handlers::rename_files`], which rewrites a manifest's file
+//! paths rather than a symbol's occurrences.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use lsp_types::{Location, Position as LspPosition, Range, TextEdit, Url, WorkspaceEdit};
+use syster_base::span::{Position, Span};
+use syster_base::Workspace;
+
+fn to_range(span: Span) -> Range {
+    Range { start: LspPosition { line: span.start.line, character: span.start.column }, end: LspPosition { line: span.end.line, character: span.end.column } }
+}
+
+/// Every location that renaming the symbol at `file`/`position` would
+/// touch: its declaration plus every reference
+/// [`Workspace::find_references`] reports. Empty (rather than `None`)
+/// when nothing resolves, matching the `textDocument/references`
+/// convention.
+pub fn rename_locations(workspace: &Workspace, file: &Path, position: Position) -> Vec<Location> {
+    workspace
+        .find_references(file, position)
+        .into_iter()
+        .filter_map(|s| Some(Location { uri: Url::from_file_path(&s.file).ok()?, range: to_range(s.range()) }))
+        .collect()
+}
+
+/// The `WorkspaceEdit` a real rename of the symbol at `file`/`position` to
+/// `new_name` would apply: one `TextEdit` per [`rename_locations`] result,
+/// grouped by file. `None` if the symbol doesn't resolve to anything.
+pub fn rename_edit(workspace: &Workspace, file: &Path, position: Position, new_name: &str) -> Option<WorkspaceEdit> {
+    let locations = rename_locations(workspace, file, position);
+    if locations.is_empty() {
+        return None;
+    }
+
+    let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+    for location in locations {
+        changes.entry(location.uri).or_default().push(TextEdit { range: location.range, new_text: new_name.to_string() });
+    }
+    Some(WorkspaceEdit { changes: Some(changes), ..Default::default() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use syster_base::semantic::{QualifiedName, RelationshipKind, Symbol, SymbolKind};
+    use syster_base::span::{Position, Span};
+
+    fn sample_workspace() -> Workspace {
+        let mut ws = Workspace::default();
+        let engine_span = Span::new(Position::new(0, 0), Position::new(0, 6));
+        let p_span = Span::new(Position::new(1, 0), Position::new(1, 1));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::engine"), SymbolKind::PartUsage, PathBuf::from("/tmp/Vehicle.sysml"), engine_span));
+        ws.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::p"), SymbolKind::PartUsage, PathBuf::from("/tmp/Vehicle.sysml"), p_span));
+        ws.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Vehicle::p"), QualifiedName::new("Vehicle::engine"));
+        ws
+    }
+
+    #[test]
+    fn rename_locations_includes_the_declaration_and_every_reference() {
+        let ws = sample_workspace();
+        let locations = rename_locations(&ws, &PathBuf::from("/tmp/Vehicle.sysml"), Position::new(0, 0));
+        assert_eq!(locations.len(), 2, "declaration plus the typing reference from Vehicle::p");
+    }
+
+    #[test]
+    fn preview_locations_match_the_workspace_edit_a_real_rename_would_produce() {
+        let ws = sample_workspace();
+        let file = PathBuf::from("/tmp/Vehicle.sysml");
+        let position = Position::new(0, 0);
+
+        let preview = rename_locations(&ws, &file, position);
+        let edit = rename_edit(&ws, &file, position, "motor").expect("a resolvable symbol produces an edit");
+
+        let edited_locations: Vec<Location> = edit
+            .changes
+            .unwrap()
+            .into_iter()
+            .flat_map(|(uri, edits)| edits.into_iter().map(move |e| Location { uri: uri.clone(), range: e.range }))
+            .collect();
+
+        assert_eq!(preview.len(), edited_locations.len());
+        for location in &preview {
+            assert!(edited_locations.contains(location), "preview location {location:?} missing from the WorkspaceEdit");
+        }
+    }
+
+    #[test]
+    fn nothing_resolves_at_an_empty_position_so_there_is_no_edit() {
+        let ws = sample_workspace();
+        let file = PathBuf::from("/tmp/Vehicle.sysml");
+        assert!(rename_edit(&ws, &file, Position::new(99, 0), "motor").is_none());
+    }
+}
@@ -0,0 +1,78 @@
+//! `workspace/willRenameFiles`.
+//!
+//! SysML/KerML references resolve by qualified name, not file path (see
+//! [`syster_base::Workspace::rename_file`]'s doc comment), so renaming or
+//! moving a `.sysml` file never invalidates a reference by itself. The one
+//! place a file's path is meaningful on disk is a project's
+//! `syster.toml`: an `include`/`exclude` entry naming the file's old
+//! relative path verbatim needs updating to the new one, or it silently
+//! stops matching anything. Glob entries (containing `*`) are left alone,
+//! since a rename doesn't change what they match.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use lsp_types::{Position as LspPosition, Range, TextEdit, Url, WorkspaceEdit};
+
+/// Builds the `WorkspaceEdit` for a `workspace/willRenameFiles` request:
+/// for each `(old, new)` pair in `renames`, rewrites any `manifest_contents`
+/// line that literally names `old` (and isn't a glob) to name `new`
+/// instead. Returns `None` when nothing in the manifest needs updating, so
+/// callers can skip publishing an empty edit.
+pub fn will_rename_files(manifest_uri: &Url, manifest_contents: &str, renames: &[(PathBuf, PathBuf)]) -> Option<WorkspaceEdit> {
+    let mut edits = Vec::new();
+    for (line_number, line) in manifest_contents.lines().enumerate() {
+        if line.contains('*') {
+            continue;
+        }
+        for (old, new) in renames {
+            let Some(old_str) = old.to_str() else { continue };
+            if !line.contains(old_str) {
+                continue;
+            }
+            edits.push(TextEdit {
+                range: Range::new(
+                    LspPosition::new(line_number as u32, 0),
+                    LspPosition::new(line_number as u32, line.chars().count() as u32),
+                ),
+                new_text: line.replace(old_str, new.to_str().unwrap_or_default()),
+            });
+        }
+    }
+
+    if edits.is_empty() {
+        return None;
+    }
+
+    let mut changes = HashMap::new();
+    changes.insert(manifest_uri.clone(), edits);
+    Some(WorkspaceEdit { changes: Some(changes), ..Default::default() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renaming_a_file_named_verbatim_in_the_manifest_updates_its_entry() {
+        let uri = Url::parse("file:///project/syster.toml").unwrap();
+        let manifest = "include = [\"Vehicle.sysml\"]\nexclude = []\n";
+
+        let edit = will_rename_files(&uri, manifest, &[(PathBuf::from("Vehicle.sysml"), PathBuf::from("Car.sysml"))])
+            .expect("the include entry names the renamed file verbatim");
+
+        let edits = &edit.changes.unwrap()[&uri];
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].new_text, "include = [\"Car.sysml\"]");
+    }
+
+    #[test]
+    fn a_glob_entry_is_left_untouched_by_a_rename() {
+        let uri = Url::parse("file:///project/syster.toml").unwrap();
+        let manifest = "include = [\"*.sysml\"]\n";
+
+        let edit = will_rename_files(&uri, manifest, &[(PathBuf::from("Vehicle.sysml"), PathBuf::from("Car.sysml"))]);
+
+        assert!(edit.is_none(), "a wildcard pattern still matches the renamed file under its new name");
+    }
+}
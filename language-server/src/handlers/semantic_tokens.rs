@@ -0,0 +1,104 @@
+//! `textDocument/semanticTokens` token ordering and overlap resolution.
+//!
+//! The LSP spec requires the tokens sent to a client to be sorted by
+//! position and non-overlapping, since the wire format encodes each token
+//! as a delta from the previous one — an out-of-order or overlapping
+//! stream corrupts every token after it. [`SemanticTokenCollector`] is the
+//! layer that guarantees this regardless of the order its callers push
+//! tokens in (e.g. a reference collected while walking a nested construct
+//! landing before its enclosing definition's own token).
+
+use syster_base::span::Span;
+
+/// One token prior to relative-delta encoding: a span plus its LSP token
+/// type index (resolved against whatever legend the server advertised
+/// during `initialize`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RawSemanticToken {
+    pub span: Span,
+    pub token_type: u32,
+}
+
+/// Collects [`RawSemanticToken`]s in whatever order they're discovered and
+/// produces a sorted, non-overlapping stream on [`Self::finish`].
+#[derive(Debug, Default)]
+pub struct SemanticTokenCollector {
+    tokens: Vec<RawSemanticToken>,
+}
+
+impl SemanticTokenCollector {
+    pub fn push(&mut self, span: Span, token_type: u32) {
+        self.tokens.push(RawSemanticToken { span, token_type });
+    }
+
+    /// Sorts the collected tokens by `(line, start column)` and drops any
+    /// token that starts before the previous (now-earlier-sorted) token
+    /// ends. Tokens starting at the same position are ordered widest span
+    /// first, then by token type, so which one survives doesn't depend on
+    /// the order they were pushed in.
+    pub fn finish(mut self) -> Vec<RawSemanticToken> {
+        self.tokens.sort_by_key(|t| (t.span.start.line, t.span.start.column, std::cmp::Reverse((t.span.end.line, t.span.end.column)), t.token_type));
+
+        let mut result: Vec<RawSemanticToken> = Vec::with_capacity(self.tokens.len());
+        for token in self.tokens {
+            if let Some(last) = result.last() {
+                let overlaps = token.span.start.line < last.span.end.line
+                    || (token.span.start.line == last.span.end.line && token.span.start.column < last.span.end.column);
+                if overlaps {
+                    continue;
+                }
+            }
+            result.push(token);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syster_base::span::Position;
+
+    fn span(start: (u32, u32), end: (u32, u32)) -> Span {
+        Span::new(Position::new(start.0, start.1), Position::new(end.0, end.1))
+    }
+
+    #[test]
+    fn tokens_pushed_out_of_order_come_back_sorted() {
+        let mut collector = SemanticTokenCollector::default();
+        collector.push(span((2, 0), (2, 5)), 1);
+        collector.push(span((0, 0), (0, 5)), 2);
+        collector.push(span((1, 0), (1, 5)), 3);
+
+        let tokens = collector.finish();
+
+        assert_eq!(tokens.iter().map(|t| t.span.start.line).collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn an_overlapping_nested_token_is_dropped_deterministically() {
+        // e.g. a reference collected inside a nested construct whose span
+        // is contained within its enclosing definition's own token.
+        let mut collector = SemanticTokenCollector::default();
+        collector.push(span((0, 0), (0, 20)), 1); // the enclosing definition
+        collector.push(span((0, 4), (0, 10)), 2); // a nested reference, overlapping
+
+        let tokens = collector.finish();
+
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].token_type, 1);
+    }
+
+    #[test]
+    fn adjacent_non_overlapping_tokens_are_both_kept() {
+        let mut collector = SemanticTokenCollector::default();
+        collector.push(span((0, 5), (0, 10)), 1);
+        collector.push(span((0, 0), (0, 5)), 2);
+
+        let tokens = collector.finish();
+
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].span.start.column, 0);
+        assert_eq!(tokens[1].span.start.column, 5);
+    }
+}
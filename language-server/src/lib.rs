@@ -0,0 +1,7 @@
+//! LSP server implementation (`syster-lsp`) built on `async-lsp`.
+
+pub mod handlers;
+pub mod log_level;
+pub mod server;
+
+pub use server::Backend;
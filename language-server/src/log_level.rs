@@ -0,0 +1,66 @@
+//! Log level resolution for the `initialize` request's `logLevel` option.
+//!
+//! This tree has no `tracing` subscriber wired up yet (no binary crate
+//! constructs one), so there's nothing here to reload at runtime. What's
+//! implemented is the configuration surface a future `main` would call
+//! once it sets one up: parsing the client-supplied `logLevel` string (or
+//! an `SYSTER_LOG` environment variable override, for users who can't edit
+//! their editor's LSP config) into a validated level, falling back to
+//! `info` on anything unrecognized rather than failing `initialize`.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    /// Parses a `logLevel` string case-insensitively, falling back to
+    /// [`LogLevel::Info`] for anything unrecognized so a typo in a client's
+    /// settings degrades gracefully instead of failing `initialize`.
+    pub fn parse(level: &str) -> Self {
+        match level.to_ascii_lowercase().as_str() {
+            "trace" => Self::Trace,
+            "debug" => Self::Debug,
+            "warn" | "warning" => Self::Warn,
+            "error" => Self::Error,
+            _ => Self::Info,
+        }
+    }
+
+    /// Resolves the effective log level for startup: the `initialize`
+    /// request's `logLevel` option if present, otherwise the `SYSTER_LOG`
+    /// environment variable, otherwise [`LogLevel::Info`].
+    pub fn resolve(init_option: Option<&str>, env_override: Option<&str>) -> Self {
+        init_option.or(env_override).map(Self::parse).unwrap_or(Self::Info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognized_levels_parse_case_insensitively() {
+        assert_eq!(LogLevel::parse("DEBUG"), LogLevel::Debug);
+        assert_eq!(LogLevel::parse("Warn"), LogLevel::Warn);
+        assert_eq!(LogLevel::parse("error"), LogLevel::Error);
+        assert_eq!(LogLevel::parse("trace"), LogLevel::Trace);
+    }
+
+    #[test]
+    fn an_invalid_level_falls_back_to_info() {
+        assert_eq!(LogLevel::parse("verbose"), LogLevel::Info);
+        assert_eq!(LogLevel::parse(""), LogLevel::Info);
+    }
+
+    #[test]
+    fn the_init_option_takes_priority_over_the_env_override() {
+        assert_eq!(LogLevel::resolve(Some("debug"), Some("error")), LogLevel::Debug);
+        assert_eq!(LogLevel::resolve(None, Some("error")), LogLevel::Error);
+        assert_eq!(LogLevel::resolve(None, None), LogLevel::Info);
+    }
+}
@@ -0,0 +1,479 @@
+//! Document lifecycle handling for the `syster-lsp` backend.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use lsp_types::{FoldingRange, MessageType, PublishDiagnosticsParams, SelectionRange, ShowMessageParams, Url};
+use syster_base::ast::AstNode;
+use syster_base::span::Position;
+use syster_base::Workspace;
+
+use crate::handlers::folding::{get_folding_ranges, get_selection_ranges, Language};
+
+/// Tracks whether an open document has a backing file on disk, so we know
+/// whether to keep it resident (for cross-file references) or drop it
+/// entirely once closed, plus the version last seen for cache invalidation.
+struct OpenDocument {
+    on_disk: bool,
+    version: i32,
+}
+
+/// A cached [`FoldingRange`] list, valid only for the document version it
+/// was computed at.
+struct CachedFolding {
+    version: i32,
+    ranges: Vec<FoldingRange>,
+}
+
+/// A cached [`SelectionRange`] chain, valid only for the document version
+/// and cursor position it was computed at — unlike folding, the result
+/// depends on where the cursor is.
+struct CachedSelection {
+    version: i32,
+    position: Position,
+    range: Option<SelectionRange>,
+}
+
+/// Per-document debounce bookkeeping for the reparse that follows a burst
+/// of `textDocument/didChange` notifications. No async runtime is wired
+/// into this crate (no `debounce::spawn`/timer task exists here), so this
+/// only tracks, per [`Url`], which scheduled parse is the *latest* one —
+/// the generation counter a real timer-based debouncer would consult
+/// before running a queued reparse, so a burst of edits to one document
+/// cancels only that document's stale work and never touches another
+/// document's pending parse.
+#[derive(Default)]
+struct PendingParses {
+    generation: HashMap<Url, u64>,
+}
+
+impl PendingParses {
+    /// Records a newly scheduled parse for `uri`, returning its
+    /// generation. A caller that later finds its generation isn't
+    /// [`Self::is_current`] anymore should skip running that parse —
+    /// callers for other URIs are never affected.
+    fn schedule(&mut self, uri: &Url) -> u64 {
+        let next = self.generation.get(uri).copied().unwrap_or(0) + 1;
+        self.generation.insert(uri.clone(), next);
+        next
+    }
+
+    /// Whether `generation` is still the latest one scheduled for `uri`.
+    /// `false` means a later edit to the same document superseded it.
+    fn is_current(&self, uri: &Url, generation: u64) -> bool {
+        self.generation.get(uri) == Some(&generation)
+    }
+}
+
+/// The long-lived LSP backend state: the loaded [`Workspace`] plus the set
+/// of currently-open documents.
+pub struct Backend {
+    workspace: Workspace,
+    open_documents: HashMap<Url, OpenDocument>,
+    folding_cache: HashMap<Url, CachedFolding>,
+    selection_cache: HashMap<Url, CachedSelection>,
+    pending_parses: PendingParses,
+    line_folding_only: bool,
+    max_folding_ranges: Option<usize>,
+    hierarchical_document_symbols: bool,
+}
+
+impl Backend {
+    pub fn new(workspace: Workspace) -> Self {
+        Self {
+            workspace,
+            open_documents: HashMap::new(),
+            folding_cache: HashMap::new(),
+            selection_cache: HashMap::new(),
+            pending_parses: PendingParses::default(),
+            line_folding_only: false,
+            max_folding_ranges: None,
+            hierarchical_document_symbols: true,
+        }
+    }
+
+    /// Records the client's folding capabilities from `initialize`:
+    /// whether it only supports whole-line folding
+    /// (`FoldingRangeClientCapabilities::line_folding_only`) and, if the
+    /// server wants to cap how many ranges it ever returns, a maximum
+    /// count. Applied by [`Self::folding_ranges`] via
+    /// [`crate::handlers::folding::apply_folding_range_limits`].
+    pub fn configure_folding(&mut self, line_folding_only: bool, max_ranges: Option<usize>) {
+        self.line_folding_only = line_folding_only;
+        self.max_folding_ranges = max_ranges;
+    }
+
+    /// Records whether the client supports hierarchical document symbols
+    /// from `initialize`'s
+    /// `DocumentSymbolClientCapabilities::hierarchical_document_symbol_support`.
+    /// Defaults to `true` (nested) until told otherwise. Applied by
+    /// [`Self::document_symbols`] via
+    /// [`crate::handlers::document_symbol::get_document_symbols`].
+    pub fn configure_document_symbols(&mut self, hierarchical: bool) {
+        self.hierarchical_document_symbols = hierarchical;
+    }
+
+    /// Handles `textDocument/documentSymbol` for `uri`/`file`: a nested
+    /// outline for a client that supports it, or a flattened
+    /// `SymbolInformation` list with `containerName`s for one that doesn't
+    /// — see [`Self::configure_document_symbols`].
+    pub fn document_symbols(&self, uri: &Url, file: &Path, options: &syster_base::DocumentSymbolOptions) -> lsp_types::DocumentSymbolResponse {
+        crate::handlers::document_symbol::get_document_symbols(&self.workspace, uri, file, options, self.hierarchical_document_symbols)
+    }
+
+    /// Schedules a debounced reparse for `uri`, returning the generation a
+    /// caller should pass to [`Self::is_parse_current`] once its debounce
+    /// delay elapses, before actually running the parse. The same
+    /// scheduling [`Self::did_change`] performs after its version/cache
+    /// bookkeeping, exposed separately for a caller that wants to schedule
+    /// without touching that bookkeeping.
+    pub fn schedule_parse(&mut self, uri: &Url) -> u64 {
+        self.pending_parses.schedule(uri)
+    }
+
+    /// Whether a generation previously returned by [`Self::schedule_parse`]
+    /// (including the one [`Self::did_change`] returns) for `uri` is still
+    /// current — i.e. no later edit to that *same* document superseded it.
+    /// A burst of edits to a different document never affects this.
+    pub fn is_parse_current(&self, uri: &Url, generation: u64) -> bool {
+        self.pending_parses.is_current(uri, generation)
+    }
+
+    pub fn did_open(&mut self, uri: Url, on_disk: bool) {
+        self.open_documents.insert(uri, OpenDocument { on_disk, version: 0 });
+    }
+
+    /// Handles `textDocument/didChange`: bumps the document's tracked
+    /// version, drops any cached folding/selection ranges for it (so the
+    /// next request recomputes from the edited content rather than serving
+    /// stale spans), and schedules a debounced reparse. The caller should
+    /// hold onto the returned generation and pass it to
+    /// [`Self::is_parse_current`] once its debounce delay elapses, skipping
+    /// the deferred reparse if a later edit to the same document already
+    /// superseded it.
+    pub fn did_change(&mut self, uri: &Url, version: i32) -> u64 {
+        if let Some(doc) = self.open_documents.get_mut(uri) {
+            doc.version = version;
+        }
+        self.folding_cache.remove(uri);
+        self.selection_cache.remove(uri);
+        self.pending_parses.schedule(uri)
+    }
+
+    /// Folding ranges for `uri`'s current `ast`, served from
+    /// [`Self::folding_cache`] when a prior computation at the same document
+    /// version is still on hand (e.g. repeated requests while the user
+    /// scrolls, with no intervening edit).
+    pub fn folding_ranges(&mut self, uri: &Url, language: Language, ast: &AstNode) -> Vec<FoldingRange> {
+        let version = self.open_documents.get(uri).map(|d| d.version).unwrap_or(0);
+        if let Some(cached) = self.folding_cache.get(uri) {
+            if cached.version == version {
+                return cached.ranges.clone();
+            }
+        }
+        let ranges = crate::handlers::folding::apply_folding_range_limits(
+            get_folding_ranges(language, ast),
+            self.line_folding_only,
+            self.max_folding_ranges,
+        );
+        self.folding_cache.insert(uri.clone(), CachedFolding { version, ranges: ranges.clone() });
+        ranges
+    }
+
+    /// Selection ranges for `position` in `uri`'s current `ast`, cached the
+    /// same way as [`Self::folding_ranges`] but additionally keyed on
+    /// `position`, since the result differs by cursor location.
+    pub fn selection_ranges(&mut self, uri: &Url, language: Language, ast: &AstNode, position: Position) -> Option<SelectionRange> {
+        let version = self.open_documents.get(uri).map(|d| d.version).unwrap_or(0);
+        if let Some(cached) = self.selection_cache.get(uri) {
+            if cached.version == version && cached.position == position {
+                return cached.range.clone();
+            }
+        }
+        let range = get_selection_ranges(language, ast, position);
+        self.selection_cache.insert(uri.clone(), CachedSelection { version, position, range: range.clone() });
+        range
+    }
+
+    /// Handles `textDocument/didClose`.
+    ///
+    /// Unsaved (non-disk-backed) files have their symbols dropped from the
+    /// workspace entirely, since nothing else can reference them once
+    /// closed. On-disk files stay resident so other open documents can keep
+    /// resolving cross-file references against them. Either way, the
+    /// closed document's diagnostics are cleared by publishing an empty
+    /// array, per the LSP spec's "clear diagnostics on close" convention.
+    pub fn did_close(&mut self, uri: Url) -> PublishDiagnosticsParams {
+        if let Some(doc) = self.open_documents.remove(&uri) {
+            if !doc.on_disk {
+                if let Ok(path) = uri.to_file_path() {
+                    self.workspace.remove_file(&path);
+                }
+            }
+        }
+        self.folding_cache.remove(&uri);
+        self.selection_cache.remove(&uri);
+
+        PublishDiagnosticsParams { uri, diagnostics: Vec::new(), version: None }
+    }
+
+    pub fn workspace(&self) -> &Workspace {
+        &self.workspace
+    }
+
+    /// Handles `workspace/willRenameFiles`: moves each renamed file's
+    /// symbols in [`Workspace`] (see [`Workspace::rename_file`]) and, if
+    /// `manifest_uri` names a readable `syster.toml`, returns a
+    /// `WorkspaceEdit` updating any of its entries that named a renamed
+    /// file by its old path (see
+    /// [`crate::handlers::rename_files::will_rename_files`]).
+    pub fn will_rename_files(&mut self, renames: &[(PathBuf, PathBuf)], manifest_uri: Option<&Url>) -> Option<lsp_types::WorkspaceEdit> {
+        for (old, new) in renames {
+            self.workspace.rename_file(old, new);
+        }
+
+        let manifest_uri = manifest_uri?;
+        let manifest_path = manifest_uri.to_file_path().ok()?;
+        let contents = std::fs::read_to_string(manifest_path).ok()?;
+        crate::handlers::rename_files::will_rename_files(manifest_uri, &contents, renames)
+    }
+
+    /// Handles `textDocument/prepareRename`-style previews: every location
+    /// renaming the symbol at `uri`/`position` would touch, without
+    /// mutating anything. Built from the same [`crate::handlers::rename`]
+    /// machinery a real `textDocument/rename` uses to build its
+    /// `WorkspaceEdit`.
+    pub fn preview_rename(&self, uri: &Url, position: Position) -> Vec<lsp_types::Location> {
+        let Some(path) = uri_to_path(uri) else { return Vec::new() };
+        crate::handlers::rename::rename_locations(&self.workspace, &path, position)
+    }
+
+    /// Loads `stdlib_path` and merges its symbols into `workspace`, for use
+    /// during `initialize`. If the path doesn't exist or fails to load, the
+    /// server still comes up — just without stdlib symbols — and the
+    /// returned `window/showMessage` warning tells the user what happened
+    /// and which path was tried, instead of failing silently.
+    pub fn with_stdlib(mut workspace: Workspace, stdlib_path: &Path) -> (Self, Option<ShowMessageParams>) {
+        if !stdlib_path.is_dir() {
+            let warning = ShowMessageParams {
+                typ: MessageType::WARNING,
+                message: format!(
+                    "standard library path '{}' does not exist; continuing without stdlib symbols",
+                    stdlib_path.display()
+                ),
+            };
+            return (Self::new(workspace), Some(warning));
+        }
+
+        match Workspace::load_dir(stdlib_path) {
+            Ok(stdlib) => {
+                for symbol in stdlib.symbols() {
+                    workspace.insert_symbol(symbol.clone());
+                }
+                (Self::new(workspace), None)
+            }
+            Err(err) => {
+                let warning = ShowMessageParams {
+                    typ: MessageType::WARNING,
+                    message: format!("failed to load standard library from '{}': {err}; continuing without stdlib symbols", stdlib_path.display()),
+                };
+                (Self::new(workspace), Some(warning))
+            }
+        }
+    }
+}
+
+pub fn uri_to_path(uri: &Url) -> Option<PathBuf> {
+    uri.to_file_path().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn did_close_publishes_empty_diagnostics() {
+        let mut backend = Backend::new(Workspace::default());
+        let uri = Url::parse("file:///tmp/untitled-1.sysml").unwrap();
+        backend.did_open(uri.clone(), false);
+
+        let params = backend.did_close(uri.clone());
+
+        assert_eq!(params.uri, uri);
+        assert!(params.diagnostics.is_empty());
+        assert!(!backend.open_documents.contains_key(&uri));
+    }
+
+    #[test]
+    fn repeated_folding_requests_without_a_change_are_served_from_cache_and_a_change_invalidates_it() {
+        use crate::handlers::folding::Language;
+        use syster_base::ast::AstNode;
+        use syster_base::semantic::SymbolKind;
+        use syster_base::span::{Position, Span};
+
+        let mut backend = Backend::new(Workspace::default());
+        let uri = Url::parse("file:///tmp/Vehicle.kerml").unwrap();
+        backend.did_open(uri.clone(), false);
+
+        let ast = AstNode::new(
+            "Vehicle",
+            SymbolKind::PartDefinition,
+            Span::new(Position::new(0, 0), Position::new(5, 1)),
+        )
+        .with_children(vec![AstNode::new("engine", SymbolKind::PartUsage, Span::new(Position::new(1, 4), Position::new(3, 5)))]);
+
+        let first = backend.folding_ranges(&uri, Language::KerML, &ast);
+        assert!(backend.folding_cache.contains_key(&uri));
+        let second = backend.folding_ranges(&uri, Language::KerML, &ast);
+
+        assert_eq!(first.len(), second.len(), "two consecutive requests without an edit should produce identical results");
+        for (a, b) in first.iter().zip(second.iter()) {
+            assert_eq!(a.start_line, b.start_line);
+            assert_eq!(a.end_line, b.end_line);
+        }
+
+        backend.did_change(&uri, 1);
+        assert!(!backend.folding_cache.contains_key(&uri), "a change should invalidate the cache");
+    }
+
+    #[test]
+    fn configure_folding_strips_character_offsets_for_line_folding_only_clients() {
+        use crate::handlers::folding::Language;
+        use syster_base::ast::AstNode;
+        use syster_base::semantic::SymbolKind;
+        use syster_base::span::{Position, Span};
+
+        let mut backend = Backend::new(Workspace::default());
+        let uri = Url::parse("file:///tmp/Vehicle.kerml").unwrap();
+        backend.did_open(uri.clone(), false);
+        backend.configure_folding(true, None);
+
+        let ast = AstNode::new(
+            "Vehicle",
+            SymbolKind::PartDefinition,
+            Span::new(Position::new(0, 0), Position::new(5, 1)),
+        )
+        .with_children(vec![AstNode::new("engine", SymbolKind::PartUsage, Span::new(Position::new(1, 4), Position::new(3, 5)))]);
+
+        let ranges = backend.folding_ranges(&uri, Language::KerML, &ast);
+        assert!(!ranges.is_empty());
+        assert!(ranges.iter().all(|r| r.start_character.is_none() && r.end_character.is_none()));
+    }
+
+    #[test]
+    fn configure_document_symbols_switches_between_nested_and_flat_responses() {
+        use lsp_types::DocumentSymbolResponse;
+        use syster_base::semantic::{QualifiedName, Symbol, SymbolKind};
+        use syster_base::span::Span;
+
+        let file = PathBuf::from("/tmp/Vehicle.sysml");
+        let mut workspace = Workspace::default();
+        workspace.insert_symbol(Symbol::new(QualifiedName::new("Vehicle"), SymbolKind::Package, file.clone(), Span::new(Position::new(0, 8), Position::new(0, 15))));
+        workspace.insert_symbol(Symbol::new(QualifiedName::new("Vehicle::Engine"), SymbolKind::PartDefinition, file.clone(), Span::new(Position::new(1, 9), Position::new(1, 15))));
+
+        let mut backend = Backend::new(workspace);
+        let uri = Url::from_file_path(&file).unwrap();
+
+        let nested = backend.document_symbols(&uri, &file, &syster_base::DocumentSymbolOptions::default());
+        assert!(matches!(nested, DocumentSymbolResponse::Nested(_)), "hierarchical support defaults to on");
+
+        backend.configure_document_symbols(false);
+        let flat = backend.document_symbols(&uri, &file, &syster_base::DocumentSymbolOptions::default());
+        let DocumentSymbolResponse::Flat(symbols) = flat else { panic!("expected a flat response once hierarchical support is off") };
+        assert_eq!(symbols.len(), 2);
+        assert_eq!(symbols.iter().find(|s| s.name == "Engine").unwrap().container_name, Some("Vehicle".to_string()));
+    }
+
+    #[test]
+    fn renaming_a_file_updates_its_symbols_and_a_literal_manifest_entry() {
+        use syster_base::semantic::{QualifiedName, Symbol, SymbolKind};
+        use syster_base::span::Span;
+
+        let old = PathBuf::from("Vehicle.sysml");
+        let new = PathBuf::from("Car.sysml");
+        let mut workspace = Workspace::default();
+        workspace.insert_symbol(Symbol::new(
+            QualifiedName::new("Vehicle"),
+            SymbolKind::PartDefinition,
+            old.clone(),
+            Span::new(Position::new(0, 0), Position::new(0, 1)),
+        ));
+        let mut backend = Backend::new(workspace);
+
+        let manifest_dir = std::env::temp_dir().join(format!("syster-will-rename-files-test-{:?}", std::thread::current().id()));
+        std::fs::create_dir_all(&manifest_dir).unwrap();
+        let manifest_path = manifest_dir.join("syster.toml");
+        std::fs::write(&manifest_path, "include = [\"Vehicle.sysml\"]\n").unwrap();
+        let manifest_uri = Url::from_file_path(&manifest_path).unwrap();
+
+        let edit = backend.will_rename_files(&[(old.clone(), new.clone())], Some(&manifest_uri)).expect("manifest names the old path");
+
+        assert_eq!(backend.workspace().symbol_by_qualified_name(&QualifiedName::new("Vehicle")).unwrap().file, new);
+        let edits = &edit.changes.unwrap()[&manifest_uri];
+        assert_eq!(edits[0].new_text, "include = [\"Car.sysml\"]");
+
+        std::fs::remove_dir_all(&manifest_dir).ok();
+    }
+
+    #[test]
+    fn preview_rename_reports_the_declaration_and_a_cross_file_reference() {
+        use syster_base::semantic::{QualifiedName, RelationshipKind, Symbol, SymbolKind};
+        use syster_base::span::Span;
+
+        let vehicle_file = PathBuf::from("/tmp/Vehicle.sysml");
+        let usage_file = PathBuf::from("/tmp/Usage.sysml");
+        let mut workspace = Workspace::default();
+        workspace.insert_symbol(Symbol::new(
+            QualifiedName::new("Vehicle::engine"),
+            SymbolKind::PartUsage,
+            vehicle_file.clone(),
+            Span::new(Position::new(0, 0), Position::new(0, 6)),
+        ));
+        workspace.insert_symbol(Symbol::new(
+            QualifiedName::new("Usage::p"),
+            SymbolKind::PartUsage,
+            usage_file,
+            Span::new(Position::new(0, 0), Position::new(0, 1)),
+        ));
+        workspace.relationships.add_edge(RelationshipKind::Typing, QualifiedName::new("Usage::p"), QualifiedName::new("Vehicle::engine"));
+        let backend = Backend::new(workspace);
+
+        let uri = Url::from_file_path(&vehicle_file).unwrap();
+        let locations = backend.preview_rename(&uri, Position::new(0, 0));
+
+        assert_eq!(locations.len(), 2, "declaration plus the typing reference from Usage::p");
+    }
+
+    #[test]
+    fn interleaved_edits_to_two_documents_are_debounced_independently() {
+        let mut backend = Backend::new(Workspace::default());
+        let a = Url::parse("file:///tmp/A.sysml").unwrap();
+        let b = Url::parse("file:///tmp/B.sysml").unwrap();
+        backend.did_open(a.clone(), false);
+        backend.did_open(b.clone(), false);
+
+        // `did_change` is the real `textDocument/didChange` entry point —
+        // it schedules the debounced reparse itself rather than leaving
+        // the caller to remember to call `schedule_parse` separately.
+        let a1 = backend.did_change(&a, 1);
+        let b1 = backend.did_change(&b, 1);
+        let a2 = backend.did_change(&a, 2);
+
+        assert!(!backend.is_parse_current(&a, a1), "a later edit to A should cancel A's stale pending parse");
+        assert!(backend.is_parse_current(&a, a2), "A's latest scheduled parse should still be current");
+        assert!(backend.is_parse_current(&b, b1), "a burst of edits to A must not cancel B's pending parse");
+    }
+
+    #[test]
+    fn invalid_stdlib_path_degrades_to_no_stdlib_and_warns_instead_of_failing() {
+        let bogus_path = std::env::temp_dir().join("syster-does-not-exist-stdlib-path");
+        std::fs::remove_dir_all(&bogus_path).ok();
+
+        let (backend, warning) = Backend::with_stdlib(Workspace::default(), &bogus_path);
+
+        let warning = warning.expect("an invalid stdlib path should produce a warning");
+        assert_eq!(warning.typ, MessageType::WARNING);
+        assert!(warning.message.contains(&bogus_path.display().to_string()));
+        assert!(backend.workspace().symbols().is_empty());
+    }
+}